@@ -0,0 +1,242 @@
+//! Derive macro for the `EguiScale` trait from the `egui-scale` crate.
+//!
+//! Use it through the `derive` feature of `egui-scale` rather than depending on this crate directly.
+
+#![forbid(unsafe_code)]
+#![forbid(missing_docs)]
+#![deny(clippy::pedantic)]
+
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Expr, Field, Fields,
+    Ident, Member, Path, Type,
+};
+
+/// Derives `EguiScale` by scaling every field of a struct or enum.
+///
+/// Fields accept the following attributes:
+///
+/// * `#[egui_scale(skip)]` - leave the field untouched.
+/// * `#[egui_scale(min = 1.0, max = 64.0)]` - clamp the field after scaling.
+///   Either bound may be given alone.
+/// * `#[egui_scale(with = path)]` - scale the field with `path(&mut field, scale)`
+///   instead of `EguiScale::scale`.
+///
+/// Types of fields that are scaled with `EguiScale::scale` and mention type parameters
+/// are required to implement `EguiScale`, other type parameters are left unbounded.
+///
+/// The container attribute `#[egui_scale(crate = path)]` sets the path
+/// to the `egui-scale` crate if it is renamed, `::egui_scale` by default.
+#[proc_macro_derive(EguiScale, attributes(egui_scale))]
+pub fn derive_egui_scale(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn derive(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(&input)?;
+    let params = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect::<HashSet<_>>();
+    let mut bounded = Vec::new();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, scale) = scale_fields(&krate, &data.fields, &params, &mut bounded)?;
+            quote! {
+                let Self #pattern = self;
+                #scale
+            }
+        }
+        Data::Enum(data) if data.variants.is_empty() => quote!(match *self {}),
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let (pattern, scale) =
+                        scale_fields(&krate, &variant.fields, &params, &mut bounded)?;
+                    Ok(quote! {
+                        Self::#ident #pattern => {
+                            #scale
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`EguiScale` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let where_clause = input.generics.make_where_clause();
+    for ty in bounded {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: #krate::EguiScale));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #krate::EguiScale for #ident #ty_generics #where_clause {
+            #[inline]
+            #[allow(unused_variables, clippy::manual_clamp)]
+            fn scale(&mut self, scale: f32) {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns path to the `egui-scale` crate from the container attributes.
+fn crate_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut krate = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("egui_scale") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate`"))
+            }
+        })?;
+    }
+
+    Ok(krate.unwrap_or_else(|| parse_quote!(::egui_scale)))
+}
+
+/// Checks whether the tokens mention any of the type parameters.
+fn mentions_params(tokens: TokenStream, params: &HashSet<String>) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.contains(&ident.to_string()),
+        TokenTree::Group(group) => mentions_params(group.stream(), params),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+/// Builds a pattern that binds all non-skipped fields and the code that scales them.
+///
+/// Types of generic fields scaled with `EguiScale` are added to `bounded`.
+fn scale_fields(
+    krate: &Path,
+    fields: &Fields,
+    params: &HashSet<String>,
+    bounded: &mut Vec<Type>,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut bindings = Vec::new();
+    let mut scale = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let field_ty = &field.ty;
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::Unnamed(index.into()), Member::Named);
+        let binding = format_ident!("__field{}", index);
+        bindings.push(quote!(#member: #binding));
+
+        if let Some(with) = &attrs.with {
+            scale.push(quote!(#with(#binding, scale);));
+        } else {
+            if mentions_params(quote!(#field_ty), params) {
+                bounded.push(field_ty.clone());
+            }
+            scale.push(quote!(#krate::EguiScale::scale(#binding, scale);));
+        }
+
+        if let Some(min) = &attrs.min {
+            scale.push(quote! {
+                if *#binding < #min {
+                    *#binding = #min;
+                }
+            });
+        }
+
+        if let Some(max) = &attrs.max {
+            scale.push(quote! {
+                if *#binding > #max {
+                    *#binding = #max;
+                }
+            });
+        }
+    }
+
+    Ok((quote!({ #(#bindings,)* .. }), quote!(#(#scale)*)))
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    with: Option<Path>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        let mut skip_span = None;
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("egui_scale") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                let ident = meta.path.get_ident().map(Ident::to_string);
+                match ident.as_deref() {
+                    Some("skip") => {
+                        attrs.skip = true;
+                        skip_span = Some(meta.path.span());
+                    }
+                    Some("min") => attrs.min = Some(meta.value()?.parse()?),
+                    Some("max") => attrs.max = Some(meta.value()?.parse()?),
+                    Some("with") => attrs.with = Some(meta.value()?.parse()?),
+                    _ => return Err(meta.error("expected `skip`, `min`, `max` or `with`")),
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(span) = skip_span {
+            if attrs.min.is_some() || attrs.max.is_some() || attrs.with.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`skip` cannot be combined with other `egui_scale` attributes",
+                ));
+            }
+        }
+
+        Ok(attrs)
+    }
+}
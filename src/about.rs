@@ -0,0 +1,78 @@
+use egui::{Pos2, Rangef, Rect, Vec2};
+
+/// A trait for scaling geometry about an origin.
+///
+/// Unlike [`EguiScale`](crate::EguiScale), which scales sizes only,
+/// this trait scales positions as well: every point moves away from
+/// or towards the origin, so the origin itself is the only fixed point.
+/// For example, to scale a [`Rect`] about its center pass `rect.center()` as the origin,
+/// and to keep its top-left corner in place pass `rect.min`.
+///
+/// Sizes such as radii, stroke widths and corner radii
+/// are scaled the same way [`EguiScale`](crate::EguiScale) scales them.
+pub trait EguiScaleAbout {
+    /// Type of the origin, a point in the same space as the value.
+    type Origin;
+
+    /// Scales the value by the given factor about the origin.
+    fn scale_about(&mut self, origin: Self::Origin, scale: f32);
+
+    /// Scales the value by the given factor about the origin and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_about(mut self, origin: Self::Origin, scale: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_about(origin, scale);
+        self
+    }
+}
+
+impl EguiScaleAbout for f32 {
+    type Origin = f32;
+
+    #[inline]
+    fn scale_about(&mut self, origin: f32, scale: f32) {
+        *self = origin + (*self - origin) * scale;
+    }
+}
+
+impl EguiScaleAbout for Pos2 {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        *self = origin + (*self - origin) * scale;
+    }
+}
+
+/// Treats the vector as a position relative to the coordinate origin.
+impl EguiScaleAbout for Vec2 {
+    type Origin = Vec2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Vec2, scale: f32) {
+        *self = origin + (*self - origin) * scale;
+    }
+}
+
+impl EguiScaleAbout for Rect {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        self.min.scale_about(origin, scale);
+        self.max.scale_about(origin, scale);
+    }
+}
+
+impl EguiScaleAbout for Rangef {
+    type Origin = f32;
+
+    #[inline]
+    fn scale_about(&mut self, origin: f32, scale: f32) {
+        self.min.scale_about(origin, scale);
+        self.max.scale_about(origin, scale);
+    }
+}
@@ -0,0 +1,131 @@
+use std::hash::Hash;
+
+use egui::{emath::easing, Context, Id};
+
+use crate::{EguiScaleContext, ScaleFactor, ScaleFactorError};
+
+/// Animates context scale between factors.
+///
+/// Call [`AnimatedScale::update`] once per frame, before adding any UI.
+/// Styles are re-derived from unscaled base styles on every animated frame,
+/// see [`EguiScaleContext`], so the animation never accumulates rounding errors.
+#[derive(Clone, Debug)]
+pub struct AnimatedScale {
+    id: Id,
+    duration: f32,
+    easing: fn(f32) -> f32,
+    from: f32,
+    target: f32,
+    applied: Option<f32>,
+    transition: u64,
+    started: bool,
+}
+
+impl AnimatedScale {
+    /// Default duration of the transition in seconds.
+    pub const DEFAULT_DURATION: f32 = 0.2;
+
+    /// Creates animated scale with the given initial factor.
+    ///
+    /// `id_salt` must be unique among animated scales of one context.
+    #[must_use]
+    pub fn new(id_salt: impl Hash, scale: f32) -> Self {
+        AnimatedScale {
+            id: Id::new(("egui_scale::AnimatedScale", id_salt)),
+            duration: Self::DEFAULT_DURATION,
+            easing: easing::cubic_out,
+            from: scale,
+            target: scale,
+            applied: None,
+            transition: 0,
+            started: false,
+        }
+    }
+
+    /// Returns this animated scale with the given transition duration in seconds.
+    #[inline]
+    #[must_use]
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Returns this animated scale with the given easing function,
+    /// see [`egui::emath::easing`].
+    #[inline]
+    #[must_use]
+    pub fn with_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the factor the animation moves to.
+    #[inline]
+    #[must_use]
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Returns the factor applied by the last [`AnimatedScale::update`].
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> f32 {
+        self.applied.unwrap_or(self.from)
+    }
+
+    /// Starts transition from the current factor to the given one.
+    pub fn set_target(&mut self, scale: f32) {
+        #![allow(clippy::float_cmp)]
+
+        if self.target != scale {
+            self.from = self.current();
+            self.target = scale;
+            self.transition += 1;
+            self.started = false;
+        }
+    }
+
+    /// Validates the factor and starts transition from the current factor to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and keeps the current target
+    /// if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    pub fn try_set_target(&mut self, scale: f32) -> Result<(), ScaleFactorError> {
+        self.set_target(ScaleFactor::new(scale)?.get());
+        Ok(())
+    }
+
+    /// Advances the animation and applies the current factor to the context.
+    ///
+    /// Returns the applied factor.
+    pub fn update(&mut self, ctx: &Context) -> f32 {
+        #![allow(clippy::float_cmp)]
+
+        // Each transition animates its own progress from 0 to 1,
+        // so retargeting mid-way continues from the currently applied factor.
+        let id = self.id.with(self.transition);
+        // A new transition registers its progress at 0 and starts moving it
+        // in the same frame, so it finishes `duration` seconds from now.
+        if !self.started {
+            self.started = true;
+            ctx.request_repaint();
+            ctx.animate_value_with_time(id, 0.0, self.duration);
+        }
+        let t = ctx.animate_value_with_time(id, 1.0, self.duration);
+
+        let scale = if t >= 1.0 {
+            self.target
+        } else {
+            egui::lerp(self.from..=self.target, (self.easing)(t))
+        };
+
+        if self.applied != Some(scale) {
+            ctx.set_scale(scale);
+            self.applied = Some(scale);
+        }
+
+        scale
+    }
+}
@@ -0,0 +1,130 @@
+use egui::Rangef;
+
+/// Per-category ranges that scaled style values are clamped to.
+///
+/// Ranges are applied after scaling, so extreme factors cannot produce
+/// unreadable fonts or saturated corner radii.
+/// Values that are zero stay zero, so e.g. a minimum spacing does not add gaps
+/// where the style has none.
+/// Every category is unbounded by default.
+///
+/// ```
+/// # use egui::Rangef;
+/// # use egui_scale::ScaleClamps;
+/// let clamps = ScaleClamps {
+///     text: Rangef::new(9.0, 72.0),
+///     stroke: Rangef::new(0.0, 4.0),
+///     corner_radius: Rangef::new(0.0, 16.0),
+///     ..ScaleClamps::NONE
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ScaleClamps {
+    /// Range of font sizes.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub text: Rangef,
+
+    /// Range of paddings, margins, indents and other small lengths
+    /// that are not covered by other categories.
+    ///
+    /// Widget widths are clamped by [`ScaleClamps::width`] instead,
+    /// so a maximum that suits paddings does not collapse text edits and sliders.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub spacing: Rangef,
+
+    /// Range of default widget widths in [`egui::style::Spacing`]:
+    /// combo boxes, sliders, text edits, menus, tooltips and the default area size.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub width: Rangef,
+
+    /// Range of non-zero stroke widths.
+    ///
+    /// Zero-width strokes are invisible and stay that way.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub stroke: Rangef,
+
+    /// Range of corner radii.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub corner_radius: Rangef,
+
+    /// Range of shadow blur and spread, and of the magnitude of shadow offsets.
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub shadow: Rangef,
+
+    /// Range of hit-target sizes in [`egui::style::Interaction`].
+    #[cfg_attr(feature = "serde", serde(with = "bounds"))]
+    pub interaction: Rangef,
+}
+
+impl Default for ScaleClamps {
+    #[inline]
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl ScaleClamps {
+    /// Clamps that leave all values unbounded.
+    pub const NONE: Self = ScaleClamps {
+        text: Rangef::EVERYTHING,
+        spacing: Rangef::EVERYTHING,
+        width: Rangef::EVERYTHING,
+        stroke: Rangef::EVERYTHING,
+        corner_radius: Rangef::EVERYTHING,
+        shadow: Rangef::EVERYTHING,
+        interaction: Rangef::EVERYTHING,
+    };
+}
+
+/// Serializes ranges with optional bounds,
+/// since infinite values are not representable in formats like JSON and TOML.
+#[cfg(feature = "serde")]
+mod bounds {
+    use egui::Rangef;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f32>,
+    }
+
+    pub fn serialize<S: Serializer>(range: &Rangef, serializer: S) -> Result<S::Ok, S::Error> {
+        // Signature is dictated by `serde(with)`.
+        #![allow(clippy::trivially_copy_pass_by_ref)]
+
+        Bounds {
+            min: range.min.is_finite().then_some(range.min),
+            max: range.max.is_finite().then_some(range.max),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rangef, D::Error> {
+        let bounds = Bounds::deserialize(deserializer)?;
+        Ok(Rangef::new(
+            bounds.min.unwrap_or(f32::NEG_INFINITY),
+            bounds.max.unwrap_or(f32::INFINITY),
+        ))
+    }
+}
+
+/// Clamps the value to the range without panicking on inverted or NaN bounds.
+///
+/// Zero values are left as is.
+#[inline]
+pub(crate) fn clamp(range: Rangef, value: f32) -> f32 {
+    if value == 0.0 {
+        value
+    } else {
+        value.max(range.min).min(range.max)
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use egui::{Context, Id, Style, Theme};
+
+use crate::{ScaleFactor, ScaleFactorError, ScaleFactors, ScaleOptions, ScaledStyle};
+
+/// Scaled styles of both themes, stored in context memory behind an [`Arc`]
+/// to keep reads cheap.
+#[derive(Clone)]
+struct ContextScale {
+    dark: ScaledStyle,
+    light: ScaledStyle,
+}
+
+impl ContextScale {
+    fn id() -> Id {
+        Id::new("egui_scale::ContextScale")
+    }
+
+    fn get(ctx: &Context) -> Option<Arc<Self>> {
+        ctx.data(|data| data.get_temp(Self::id()))
+    }
+
+    fn load(ctx: &Context) -> Self {
+        Self::get(ctx).map_or_else(
+            || ContextScale {
+                dark: ScaledStyle::new((*ctx.style_of(Theme::Dark)).clone()),
+                light: ScaledStyle::new((*ctx.style_of(Theme::Light)).clone()),
+            },
+            |scale| (*scale).clone(),
+        )
+    }
+
+    fn theme(&self, theme: Theme) -> &ScaledStyle {
+        match theme {
+            Theme::Dark => &self.dark,
+            Theme::Light => &self.light,
+        }
+    }
+
+    fn store(self, ctx: &Context) {
+        ctx.options_mut(|options| {
+            options.dark_style = self.dark.style().clone().into();
+            options.light_style = self.light.style().clone().into();
+        });
+        ctx.data_mut(|data| data.insert_temp(Self::id(), Arc::new(self)));
+    }
+}
+
+/// Extension trait for [`Context`] that scales styles of both themes.
+///
+/// Unscaled styles are captured from the context the first time the scale is set
+/// and every later change derives scaled styles from them,
+/// so changing the scale never compounds.
+///
+/// Because of that, changes made directly to the context styles
+/// are overwritten the next time the scale changes.
+/// Use [`EguiScaleContext::all_unscaled_styles_mut`] to change styles while scaled.
+pub trait EguiScaleContext {
+    /// Scales styles of both themes by the given factor.
+    ///
+    /// Keeps rounding, hairline and snapping policies of current scale options.
+    /// Use [`EguiScaleContext::try_set_scale`] for factors from user input or config files.
+    fn set_scale(&self, scale: f32);
+
+    /// Scales styles of both themes by the given factor after validating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves styles untouched
+    /// if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    fn try_set_scale(&self, scale: f32) -> Result<(), ScaleFactorError> {
+        self.set_scale(ScaleFactor::new(scale)?.get());
+        Ok(())
+    }
+
+    /// Scales styles of both themes according to the given options.
+    fn set_scale_options(&self, options: ScaleOptions);
+
+    /// Returns current scale options.
+    fn scale_options(&self) -> ScaleOptions;
+
+    /// Returns current scale factor of layout lengths,
+    /// i.e. the spacing factor of current scale options.
+    fn scale_factor(&self) -> f32;
+
+    /// Returns the unscaled style of the given theme.
+    fn unscaled_style_of(&self, theme: Theme) -> Style;
+
+    /// Mutates unscaled styles of both themes and rescales them.
+    fn all_unscaled_styles_mut(&self, f: impl FnMut(&mut Style));
+
+    /// Restores unscaled styles and forgets them,
+    /// so that the next scale change captures context styles again.
+    fn reset_scale(&self);
+}
+
+impl EguiScaleContext for Context {
+    fn set_scale(&self, scale: f32) {
+        let mut options = self.scale_options();
+        options.factors = ScaleFactors::uniform(scale);
+        self.set_scale_options(options);
+    }
+
+    fn set_scale_options(&self, options: ScaleOptions) {
+        let mut scale = ContextScale::load(self);
+        scale.dark.set_options(options);
+        scale.light.set_options(options);
+        scale.store(self);
+    }
+
+    fn scale_options(&self) -> ScaleOptions {
+        ContextScale::get(self).map_or_else(ScaleOptions::default, |scale| *scale.dark.options())
+    }
+
+    fn scale_factor(&self) -> f32 {
+        self.scale_options().factors.spacing
+    }
+
+    fn unscaled_style_of(&self, theme: Theme) -> Style {
+        match ContextScale::get(self) {
+            Some(scale) => scale.theme(theme).base().clone(),
+            None => (*self.style_of(theme)).clone(),
+        }
+    }
+
+    fn all_unscaled_styles_mut(&self, mut f: impl FnMut(&mut Style)) {
+        let mut scale = ContextScale::load(self);
+        scale.dark.modify_base(&mut f);
+        scale.light.modify_base(&mut f);
+        scale.store(self);
+    }
+
+    fn reset_scale(&self) {
+        if let Some(scale) = ContextScale::get(self) {
+            self.data_mut(|data| data.remove::<Arc<ContextScale>>(ContextScale::id()));
+            self.set_style_of(Theme::Dark, scale.dark.base().clone());
+            self.set_style_of(Theme::Light, scale.light.base().clone());
+        }
+    }
+}
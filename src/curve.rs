@@ -0,0 +1,107 @@
+use crate::ScaleFactors;
+
+/// Maps a user-facing zoom value to a scale factor.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScaleCurve {
+    /// Factor equals the zoom.
+    #[default]
+    Linear,
+
+    /// Factor is `zoom.powf(exponent)`.
+    ///
+    /// Exponents below `1.0` make the category grow slower than the zoom,
+    /// and exponents above `1.0` make it grow faster.
+    Power(f32),
+
+    /// Piecewise linear interpolation between `(zoom, factor)` points sorted by zoom.
+    ///
+    /// Zoom values outside of the table are clamped to its first and last points.
+    /// An empty table behaves like [`ScaleCurve::Linear`].
+    Table(Vec<(f32, f32)>),
+}
+
+impl ScaleCurve {
+    /// Returns the scale factor for the given zoom.
+    #[must_use]
+    pub fn factor(&self, zoom: f32) -> f32 {
+        match self {
+            ScaleCurve::Linear => zoom,
+            ScaleCurve::Power(exponent) => zoom.powf(*exponent),
+            ScaleCurve::Table(points) => {
+                let Some(&(first_zoom, first_factor)) = points.first() else {
+                    return zoom;
+                };
+                if zoom <= first_zoom {
+                    return first_factor;
+                }
+
+                for window in points.windows(2) {
+                    let (from_zoom, from_factor) = window[0];
+                    let (to_zoom, to_factor) = window[1];
+                    if zoom <= to_zoom {
+                        let t = (zoom - from_zoom) / (to_zoom - from_zoom);
+                        return egui::lerp(from_factor..=to_factor, t);
+                    }
+                }
+
+                points[points.len() - 1].1
+            }
+        }
+    }
+}
+
+/// Per-category curves that derive [`ScaleFactors`] from a single zoom value.
+///
+/// For example, at high zoom for low-vision users text can grow
+/// faster than spacing, so panels don't become enormous:
+///
+/// ```
+/// # use egui_scale::{ScaleCurve, ScaleCurves};
+/// let curves = ScaleCurves {
+///     spacing: ScaleCurve::Power(0.7),
+///     ..ScaleCurves::default()
+/// };
+/// let factors = curves.factors(3.0);
+/// assert!(factors.text > factors.spacing);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ScaleCurves {
+    /// Curve for [`ScaleFactors::text`].
+    pub text: ScaleCurve,
+
+    /// Curve for [`ScaleFactors::spacing`].
+    pub spacing: ScaleCurve,
+
+    /// Curve for [`ScaleFactors::stroke`].
+    pub stroke: ScaleCurve,
+
+    /// Curve for [`ScaleFactors::corner_radius`].
+    pub corner_radius: ScaleCurve,
+
+    /// Curve for [`ScaleFactors::shadow`].
+    pub shadow: ScaleCurve,
+
+    /// Curve for [`ScaleFactors::interaction`].
+    pub interaction: ScaleCurve,
+}
+
+impl ScaleCurves {
+    /// Returns per-category factors for the given zoom.
+    #[must_use]
+    pub fn factors(&self, zoom: f32) -> ScaleFactors {
+        ScaleFactors {
+            text: self.text.factor(zoom),
+            spacing: self.spacing.factor(zoom),
+            stroke: self.stroke.factor(zoom),
+            corner_radius: self.corner_radius.factor(zoom),
+            shadow: self.shadow.factor(zoom),
+            interaction: self.interaction.factor(zoom),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use egui::Style;
+
+use crate::{EguiScaleWith, ScaleFactors, ScaleOptions};
+
+/// Named density presets that change only spacing of the style.
+///
+/// Density scales item spacing, button padding, window and menu margins, indent
+/// and interact size in [`egui::style::Spacing`], together with its [`egui::style::ScrollStyle`].
+/// Widget widths, icons, fonts, strokes, corner radii, shadows,
+/// visuals and scroll animation are left alone.
+/// Apply it to the unscaled style, then scale the result by an overall zoom factor:
+///
+/// ```
+/// # use egui_scale::{Density, EguiScale};
+/// let style = Density::Compact.applied(egui::Style::default()).scaled(1.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Density {
+    /// Tight spacing for data-heavy screens.
+    Compact,
+
+    /// Spacing as defined by the style.
+    #[default]
+    Comfortable,
+
+    /// Loose spacing.
+    Spacious,
+}
+
+impl Density {
+    /// Returns factor applied to spacing of the style.
+    #[inline]
+    #[must_use]
+    pub const fn spacing(self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.25,
+        }
+    }
+
+    /// Scales spacing of the style according to this density.
+    ///
+    /// Densities compound, so apply it once to a style that has no density applied.
+    pub fn apply(self, style: &mut Style) {
+        let options = ScaleOptions::new(ScaleFactors {
+            spacing: self.spacing(),
+            ..ScaleFactors::IDENTITY
+        });
+
+        let spacing = &mut style.spacing;
+        options.size(&mut spacing.item_spacing);
+        options.size(&mut spacing.button_padding);
+        options.margin(&mut spacing.window_margin);
+        options.margin(&mut spacing.menu_margin);
+        options.length(&mut spacing.indent);
+        options.size(&mut spacing.interact_size);
+        spacing.scroll.scale_with_options(&options);
+    }
+
+    /// Scales spacing of the style according to this density and returns it.
+    #[inline]
+    #[must_use]
+    pub fn applied(self, mut style: Style) -> Style {
+        self.apply(&mut style);
+        style
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+/// Scale factor that is known to be finite and positive.
+///
+/// Use it to validate factors loaded from configuration files or user input
+/// before they are applied, see [`EguiScale::try_scale`](crate::EguiScale::try_scale).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f32", into = "f32")
+)]
+pub struct ScaleFactor(f32);
+
+impl Default for ScaleFactor {
+    #[inline]
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl ScaleFactor {
+    /// Factor that leaves values unchanged.
+    pub const ONE: Self = ScaleFactor(1.0);
+
+    /// Validates the factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    pub fn new(value: f32) -> Result<Self, ScaleFactorError> {
+        if value.is_nan() {
+            Err(ScaleFactorError::NaN)
+        } else if value.is_infinite() {
+            Err(ScaleFactorError::Infinite(value))
+        } else if value == 0.0 {
+            Err(ScaleFactorError::Zero)
+        } else if value < 0.0 {
+            Err(ScaleFactorError::Negative(value))
+        } else {
+            Ok(ScaleFactor(value))
+        }
+    }
+
+    /// Returns the factor value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
+}
+
+impl TryFrom<f32> for ScaleFactor {
+    type Error = ScaleFactorError;
+
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, ScaleFactorError> {
+        ScaleFactor::new(value)
+    }
+}
+
+impl From<ScaleFactor> for f32 {
+    #[inline]
+    fn from(factor: ScaleFactor) -> f32 {
+        factor.0
+    }
+}
+
+impl fmt::Display for ScaleFactor {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Error returned when a value is not a valid [`ScaleFactor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScaleFactorError {
+    /// The factor is NaN.
+    NaN,
+
+    /// The factor is positive or negative infinity.
+    Infinite(f32),
+
+    /// The factor is zero, which would collapse all sizes.
+    Zero,
+
+    /// The factor is negative.
+    Negative(f32),
+}
+
+impl fmt::Display for ScaleFactorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("scale factor must be finite and positive, ")?;
+        match self {
+            ScaleFactorError::NaN => f.write_str("got NaN"),
+            ScaleFactorError::Infinite(value) | ScaleFactorError::Negative(value) => {
+                write!(f, "got {value}")
+            }
+            ScaleFactorError::Zero => f.write_str("got zero"),
+        }
+    }
+}
+
+impl std::error::Error for ScaleFactorError {}
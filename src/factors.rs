@@ -0,0 +1,72 @@
+/// Separate scale factors for different categories of style values.
+///
+/// Unlike a single factor passed to [`EguiScale::scale`](crate::EguiScale::scale), this allows
+/// to scale e.g. text without touching padding, or make strokes thicker
+/// while keeping everything else as is.
+///
+/// With the `serde` feature enabled, deserialized factors are validated
+/// like a [`ScaleFactor`](crate::ScaleFactor).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ScaleFactors {
+    /// Factor for font sizes in [`egui::Style::text_styles`] and [`egui::Style::override_font_id`].
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub text: f32,
+
+    /// Factor for lengths in [`egui::style::Spacing`] and other sizes that are not covered by other categories.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub spacing: f32,
+
+    /// Factor for [`egui::Stroke`] widths.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub stroke: f32,
+
+    /// Factor for [`egui::CornerRadius`] values.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub corner_radius: f32,
+
+    /// Factor for [`egui::Shadow`] offsets, blur and spread.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub shadow: f32,
+
+    /// Factor for hit-target sizes in [`egui::style::Interaction`].
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "factor"))]
+    pub interaction: f32,
+}
+
+/// Deserializes a factor and rejects values that are not a valid [`ScaleFactor`](crate::ScaleFactor).
+#[cfg(feature = "serde")]
+fn factor<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    <crate::ScaleFactor as serde::Deserialize>::deserialize(deserializer)
+        .map(crate::ScaleFactor::get)
+}
+
+impl Default for ScaleFactors {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl ScaleFactors {
+    /// Factors that leave all values unchanged.
+    pub const IDENTITY: Self = Self::uniform(1.0);
+
+    /// Returns factors that scale all categories by the same factor.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(scale: f32) -> Self {
+        ScaleFactors {
+            text: scale,
+            spacing: scale,
+            stroke: scale,
+            corner_radius: scale,
+            shadow: scale,
+            interaction: scale,
+        }
+    }
+}
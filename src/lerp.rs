@@ -0,0 +1,422 @@
+use egui::{
+    epaint::{AlphaFromCoverage, Shadow},
+    style::{
+        Interaction, ScrollAnimation, ScrollStyle, Selection, Spacing, TextCursorStyle,
+        WidgetVisuals, Widgets,
+    },
+    Color32, CornerRadius, FontId, Frame, Margin, Rangef, Stroke, Style, Vec2, Visuals,
+};
+
+/// A trait for blending two values of the same type.
+///
+/// Numbers, sizes and colors are interpolated linearly, colors in gamma space.
+/// Values that cannot be interpolated, like flags, enums and font families,
+/// are taken from `self` while `t < 0.5` and from `other` after that.
+///
+/// Together with [`EguiScale`](crate::EguiScale) this allows animating
+/// between e.g. compact and comfortable layouts, or between themes.
+pub trait EguiLerp {
+    /// Moves the value towards `other` by `t`,
+    /// where `0.0` keeps the value and `1.0` makes it equal to `other`.
+    fn lerp(&mut self, other: &Self, t: f32);
+
+    /// Moves the value towards `other` by `t` and return the modified value.
+    #[inline]
+    #[must_use]
+    fn lerped(mut self, other: &Self, t: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self.lerp(other, t);
+        self
+    }
+}
+
+/// Takes `other` for values that cannot be interpolated.
+#[inline]
+fn pick<T: Clone>(value: &mut T, other: &T, t: f32) {
+    if t >= 0.5 {
+        value.clone_from(other);
+    }
+}
+
+impl EguiLerp for f32 {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        *self = egui::lerp(*self..=*other, t);
+    }
+}
+
+impl EguiLerp for u8 {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        #![allow(clippy::cast_possible_truncation)]
+        #![allow(clippy::cast_sign_loss)]
+
+        *self = egui::lerp(f32::from(*self)..=f32::from(*other), t).round() as u8;
+    }
+}
+
+impl EguiLerp for i8 {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        #![allow(clippy::cast_possible_truncation)]
+
+        *self = egui::lerp(f32::from(*self)..=f32::from(*other), t).round() as i8;
+    }
+}
+
+impl EguiLerp for Vec2 {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.x.lerp(&other.x, t);
+        self.y.lerp(&other.y, t);
+    }
+}
+
+impl EguiLerp for Rangef {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.min.lerp(&other.min, t);
+        self.max.lerp(&other.max, t);
+    }
+}
+
+impl EguiLerp for Color32 {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        *self = self.lerp_to_gamma(*other, t);
+    }
+}
+
+impl<T> EguiLerp for Option<T>
+where
+    T: EguiLerp + Clone,
+{
+    /// Interpolates if both values are `Some`, otherwise picks one of them.
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        match (self.as_mut(), other) {
+            (Some(value), Some(other)) => value.lerp(other, t),
+            _ => pick(self, other, t),
+        }
+    }
+}
+
+impl EguiLerp for CornerRadius {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.nw.lerp(&other.nw, t);
+        self.ne.lerp(&other.ne, t);
+        self.sw.lerp(&other.sw, t);
+        self.se.lerp(&other.se, t);
+    }
+}
+
+impl EguiLerp for Margin {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.left.lerp(&other.left, t);
+        self.right.lerp(&other.right, t);
+        self.top.lerp(&other.top, t);
+        self.bottom.lerp(&other.bottom, t);
+    }
+}
+
+impl EguiLerp for Stroke {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.width.lerp(&other.width, t);
+        self.color.lerp(&other.color, t);
+    }
+}
+
+impl EguiLerp for Shadow {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.offset[0].lerp(&other.offset[0], t);
+        self.offset[1].lerp(&other.offset[1], t);
+        self.blur.lerp(&other.blur, t);
+        self.spread.lerp(&other.spread, t);
+        self.color.lerp(&other.color, t);
+    }
+}
+
+impl EguiLerp for FontId {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.size.lerp(&other.size, t);
+        pick(&mut self.family, &other.family, t);
+    }
+}
+
+impl EguiLerp for AlphaFromCoverage {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        match (self, other) {
+            (AlphaFromCoverage::Gamma(gamma), AlphaFromCoverage::Gamma(other)) => {
+                gamma.lerp(other, t);
+            }
+            (value, other) => pick(value, other, t),
+        }
+    }
+}
+
+impl EguiLerp for WidgetVisuals {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.bg_fill.lerp(&other.bg_fill, t);
+        self.weak_bg_fill.lerp(&other.weak_bg_fill, t);
+        self.bg_stroke.lerp(&other.bg_stroke, t);
+        self.corner_radius.lerp(&other.corner_radius, t);
+        self.fg_stroke.lerp(&other.fg_stroke, t);
+        self.expansion.lerp(&other.expansion, t);
+    }
+}
+
+impl EguiLerp for Widgets {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.noninteractive.lerp(&other.noninteractive, t);
+        self.inactive.lerp(&other.inactive, t);
+        self.hovered.lerp(&other.hovered, t);
+        self.active.lerp(&other.active, t);
+        self.open.lerp(&other.open, t);
+    }
+}
+
+impl EguiLerp for Selection {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.bg_fill.lerp(&other.bg_fill, t);
+        self.stroke.lerp(&other.stroke, t);
+    }
+}
+
+impl EguiLerp for TextCursorStyle {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.stroke.lerp(&other.stroke, t);
+        pick(&mut self.preview, &other.preview, t);
+        pick(&mut self.blink, &other.blink, t);
+        self.on_duration.lerp(&other.on_duration, t);
+        self.off_duration.lerp(&other.off_duration, t);
+    }
+}
+
+impl EguiLerp for Interaction {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.interact_radius.lerp(&other.interact_radius, t);
+        self.resize_grab_radius_side
+            .lerp(&other.resize_grab_radius_side, t);
+        self.resize_grab_radius_corner
+            .lerp(&other.resize_grab_radius_corner, t);
+        pick(
+            &mut self.show_tooltips_only_when_still,
+            &other.show_tooltips_only_when_still,
+            t,
+        );
+        self.tooltip_delay.lerp(&other.tooltip_delay, t);
+        self.tooltip_grace_time.lerp(&other.tooltip_grace_time, t);
+        pick(&mut self.selectable_labels, &other.selectable_labels, t);
+        pick(
+            &mut self.multi_widget_text_select,
+            &other.multi_widget_text_select,
+            t,
+        );
+    }
+}
+
+impl EguiLerp for Visuals {
+    fn lerp(&mut self, other: &Self, t: f32) {
+        pick(&mut self.dark_mode, &other.dark_mode, t);
+        self.text_alpha_from_coverage
+            .lerp(&other.text_alpha_from_coverage, t);
+        self.override_text_color.lerp(&other.override_text_color, t);
+        self.weak_text_alpha.lerp(&other.weak_text_alpha, t);
+        self.weak_text_color.lerp(&other.weak_text_color, t);
+        self.widgets.lerp(&other.widgets, t);
+        self.selection.lerp(&other.selection, t);
+        self.hyperlink_color.lerp(&other.hyperlink_color, t);
+        self.faint_bg_color.lerp(&other.faint_bg_color, t);
+        self.extreme_bg_color.lerp(&other.extreme_bg_color, t);
+        self.text_edit_bg_color.lerp(&other.text_edit_bg_color, t);
+        self.code_bg_color.lerp(&other.code_bg_color, t);
+        self.warn_fg_color.lerp(&other.warn_fg_color, t);
+        self.error_fg_color.lerp(&other.error_fg_color, t);
+        self.window_corner_radius
+            .lerp(&other.window_corner_radius, t);
+        self.window_shadow.lerp(&other.window_shadow, t);
+        self.window_fill.lerp(&other.window_fill, t);
+        self.window_stroke.lerp(&other.window_stroke, t);
+        pick(
+            &mut self.window_highlight_topmost,
+            &other.window_highlight_topmost,
+            t,
+        );
+        self.menu_corner_radius.lerp(&other.menu_corner_radius, t);
+        self.panel_fill.lerp(&other.panel_fill, t);
+        self.popup_shadow.lerp(&other.popup_shadow, t);
+        self.resize_corner_size.lerp(&other.resize_corner_size, t);
+        self.text_cursor.lerp(&other.text_cursor, t);
+        self.clip_rect_margin.lerp(&other.clip_rect_margin, t);
+        pick(&mut self.button_frame, &other.button_frame, t);
+        pick(
+            &mut self.collapsing_header_frame,
+            &other.collapsing_header_frame,
+            t,
+        );
+        pick(
+            &mut self.indent_has_left_vline,
+            &other.indent_has_left_vline,
+            t,
+        );
+        pick(&mut self.striped, &other.striped, t);
+        pick(
+            &mut self.slider_trailing_fill,
+            &other.slider_trailing_fill,
+            t,
+        );
+        pick(&mut self.handle_shape, &other.handle_shape, t);
+        pick(&mut self.interact_cursor, &other.interact_cursor, t);
+        pick(
+            &mut self.image_loading_spinners,
+            &other.image_loading_spinners,
+            t,
+        );
+        pick(&mut self.numeric_color_space, &other.numeric_color_space, t);
+        self.disabled_alpha.lerp(&other.disabled_alpha, t);
+    }
+}
+
+impl EguiLerp for ScrollStyle {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        pick(&mut self.floating, &other.floating, t);
+        self.bar_width.lerp(&other.bar_width, t);
+        self.handle_min_length.lerp(&other.handle_min_length, t);
+        self.bar_inner_margin.lerp(&other.bar_inner_margin, t);
+        self.bar_outer_margin.lerp(&other.bar_outer_margin, t);
+        self.floating_width.lerp(&other.floating_width, t);
+        self.floating_allocated_width
+            .lerp(&other.floating_allocated_width, t);
+        pick(&mut self.foreground_color, &other.foreground_color, t);
+        self.dormant_background_opacity
+            .lerp(&other.dormant_background_opacity, t);
+        self.active_background_opacity
+            .lerp(&other.active_background_opacity, t);
+        self.interact_background_opacity
+            .lerp(&other.interact_background_opacity, t);
+        self.dormant_handle_opacity
+            .lerp(&other.dormant_handle_opacity, t);
+        self.active_handle_opacity
+            .lerp(&other.active_handle_opacity, t);
+        self.interact_handle_opacity
+            .lerp(&other.interact_handle_opacity, t);
+    }
+}
+
+impl EguiLerp for ScrollAnimation {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.points_per_second.lerp(&other.points_per_second, t);
+        self.duration.lerp(&other.duration, t);
+    }
+}
+
+impl EguiLerp for Spacing {
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.item_spacing.lerp(&other.item_spacing, t);
+        self.window_margin.lerp(&other.window_margin, t);
+        self.button_padding.lerp(&other.button_padding, t);
+        self.menu_margin.lerp(&other.menu_margin, t);
+        self.indent.lerp(&other.indent, t);
+        self.interact_size.lerp(&other.interact_size, t);
+        self.slider_width.lerp(&other.slider_width, t);
+        self.slider_rail_height.lerp(&other.slider_rail_height, t);
+        self.combo_width.lerp(&other.combo_width, t);
+        self.text_edit_width.lerp(&other.text_edit_width, t);
+        self.icon_width.lerp(&other.icon_width, t);
+        self.icon_width_inner.lerp(&other.icon_width_inner, t);
+        self.icon_spacing.lerp(&other.icon_spacing, t);
+        self.default_area_size.lerp(&other.default_area_size, t);
+        self.tooltip_width.lerp(&other.tooltip_width, t);
+        self.menu_width.lerp(&other.menu_width, t);
+        self.menu_spacing.lerp(&other.menu_spacing, t);
+        pick(
+            &mut self.indent_ends_with_horizontal_line,
+            &other.indent_ends_with_horizontal_line,
+            t,
+        );
+        self.combo_height.lerp(&other.combo_height, t);
+        self.scroll.lerp(&other.scroll, t);
+    }
+}
+
+impl EguiLerp for Style {
+    /// Text styles present in both styles are interpolated,
+    /// the set of text styles is picked like other discrete values.
+    fn lerp(&mut self, other: &Self, t: f32) {
+        pick(&mut self.override_text_style, &other.override_text_style, t);
+        self.override_font_id.lerp(&other.override_font_id, t);
+        pick(
+            &mut self.override_text_valign,
+            &other.override_text_valign,
+            t,
+        );
+        for (text_style, font_id) in &mut self.text_styles {
+            if let Some(other) = other.text_styles.get(text_style) {
+                font_id.lerp(other, t);
+            }
+        }
+        if t >= 0.5 {
+            self.text_styles
+                .retain(|text_style, _| other.text_styles.contains_key(text_style));
+            for (text_style, font_id) in &other.text_styles {
+                self.text_styles
+                    .entry(text_style.clone())
+                    .or_insert_with(|| font_id.clone());
+            }
+        }
+        pick(
+            &mut self.drag_value_text_style,
+            &other.drag_value_text_style,
+            t,
+        );
+        pick(&mut self.number_formatter, &other.number_formatter, t);
+        #[allow(deprecated)]
+        pick(&mut self.wrap, &other.wrap, t);
+        pick(&mut self.wrap_mode, &other.wrap_mode, t);
+        self.spacing.lerp(&other.spacing, t);
+        self.interaction.lerp(&other.interaction, t);
+        self.visuals.lerp(&other.visuals, t);
+        self.animation_time.lerp(&other.animation_time, t);
+        pick(&mut self.debug, &other.debug, t);
+        pick(
+            &mut self.explanation_tooltips,
+            &other.explanation_tooltips,
+            t,
+        );
+        pick(&mut self.url_in_tooltip, &other.url_in_tooltip, t);
+        pick(
+            &mut self.always_scroll_the_only_direction,
+            &other.always_scroll_the_only_direction,
+            t,
+        );
+        self.scroll_animation.lerp(&other.scroll_animation, t);
+        pick(&mut self.compact_menu_style, &other.compact_menu_style, t);
+    }
+}
+
+impl EguiLerp for Frame {
+    #[inline]
+    fn lerp(&mut self, other: &Self, t: f32) {
+        self.inner_margin.lerp(&other.inner_margin, t);
+        self.fill.lerp(&other.fill, t);
+        self.stroke.lerp(&other.stroke, t);
+        self.corner_radius.lerp(&other.corner_radius, t);
+        self.outer_margin.lerp(&other.outer_margin, t);
+        self.shadow.lerp(&other.shadow, t);
+    }
+}
@@ -7,12 +7,76 @@
 #![forbid(missing_docs)]
 #![deny(clippy::pedantic)]
 
+mod about;
+mod animated;
+mod clamps;
+mod context;
+mod curve;
+mod density;
+mod factor;
+mod factors;
+mod lerp;
+mod options;
+mod profile;
+mod rounding;
+mod scaled;
+mod shape;
+mod stroke;
+mod style;
+mod text;
+mod time;
+mod type_scale;
+mod ui;
+#[cfg(feature = "watch")]
+mod watch;
+mod xy;
+
 use egui::{
-    epaint::Shadow,
+    epaint::{PathStroke, Shadow},
     style::{Interaction, ScrollStyle, Spacing, TextCursorStyle, WidgetVisuals, Widgets},
     CornerRadius, FontId, Frame, Margin, Stroke, Style, Vec2, Visuals,
 };
 
+/// Derive macro that implements [`EguiScale`] by scaling every field.
+///
+/// Fields can be annotated with `#[egui_scale(skip)]`, `#[egui_scale(min = .., max = ..)]`
+/// to clamp the scaled value, or `#[egui_scale(with = path)]` to scale the field
+/// with `path(&mut field, scale)`.
+/// If this crate is renamed, point the macro to it with `#[egui_scale(crate = path)]` on the type.
+#[cfg(feature = "derive")]
+pub use egui_scale_derive::EguiScale;
+
+/// Compiles examples from the readme.
+#[cfg(all(doctest, feature = "derive"))]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
+
+pub use self::{
+    about::EguiScaleAbout,
+    animated::AnimatedScale,
+    clamps::ScaleClamps,
+    context::EguiScaleContext,
+    curve::{ScaleCurve, ScaleCurves},
+    density::Density,
+    factor::{ScaleFactor, ScaleFactorError},
+    factors::ScaleFactors,
+    lerp::EguiLerp,
+    options::ScaleOptions,
+    profile::ScaleProfile,
+    rounding::{EguiScaleRounded, RoundingMode, RoundingPolicy},
+    scaled::ScaledStyle,
+    stroke::{FadeSpace, HairlinePolicy},
+    style::EguiScaleWith,
+    text::EguiScaleText,
+    time::EguiScaleTime,
+    type_scale::TypeScale,
+    ui::EguiScaleUi,
+    xy::EguiScaleXY,
+};
+
+#[cfg(feature = "watch")]
+pub use self::watch::{ProfileParseError, ProfileWatchError, ProfileWatcher};
+
 /// A trait for scaling various types in the `egui` library.
 pub trait EguiScale {
     /// Scales the value by the given factor.
@@ -28,6 +92,34 @@ pub trait EguiScale {
         self.scale(scale);
         self
     }
+
+    /// Scales the value by the given factor after validating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the value untouched
+    /// if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    fn try_scale(&mut self, scale: f32) -> Result<(), ScaleFactorError> {
+        let scale = ScaleFactor::new(scale)?;
+        self.scale(scale.get());
+        Ok(())
+    }
+
+    /// Scales the value by the given factor after validating it
+    /// and return the modified value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    fn try_scaled(mut self, scale: f32) -> Result<Self, ScaleFactorError>
+    where
+        Self: Sized,
+    {
+        self.try_scale(scale)?;
+        Ok(self)
+    }
 }
 
 impl EguiScale for f32 {
@@ -40,19 +132,14 @@ impl EguiScale for f32 {
 impl EguiScale for u8 {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        #![allow(clippy::cast_possible_truncation)]
-        #![allow(clippy::cast_sign_loss)]
-
-        *self = (f32::from(*self) * scale) as u8;
+        self.scale_rounded(scale, RoundingPolicy::TRUNCATE);
     }
 }
 
 impl EguiScale for i8 {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        #![allow(clippy::cast_possible_truncation)]
-
-        *self = (f32::from(*self) * scale) as i8;
+        self.scale_rounded(scale, RoundingPolicy::TRUNCATE);
     }
 }
 
@@ -104,96 +191,63 @@ impl EguiScale for Shadow {
 impl EguiScale for Stroke {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.width *= scale;
-        if self.width < 1.0 {
-            self.color.gamma_multiply(self.width);
-            self.width = 1.0;
-        }
+        HairlinePolicy::FADE.scale_stroke(self, scale);
+    }
+}
+
+impl EguiScale for PathStroke {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        HairlinePolicy::FADE.scale_path_stroke(self, scale);
     }
 }
 
 impl EguiScale for WidgetVisuals {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.bg_stroke.scale(scale);
-        self.corner_radius.scale(scale);
-        self.fg_stroke.scale(scale);
-        self.expansion.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for Interaction {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.resize_grab_radius_corner.scale(scale);
-        self.resize_grab_radius_side.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for Widgets {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.noninteractive.scale(scale);
-        self.inactive.scale(scale);
-        self.hovered.scale(scale);
-        self.active.scale(scale);
-        self.open.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for TextCursorStyle {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.stroke.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for Visuals {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.clip_rect_margin.scale(scale);
-        self.menu_corner_radius.scale(scale);
-        self.popup_shadow.scale(scale);
-        self.resize_corner_size.scale(scale);
-        self.selection.stroke.scale(scale);
-        self.text_cursor.scale(scale);
-        self.widgets.scale(scale);
-        self.window_corner_radius.scale(scale);
-        self.window_shadow.scale(scale);
-        self.window_stroke.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for ScrollStyle {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.bar_inner_margin.scale(scale);
-        self.bar_outer_margin.scale(scale);
-        self.bar_width.scale(scale);
-        self.floating_allocated_width.scale(scale);
-        self.floating_width.scale(scale);
-        self.handle_min_length.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
 impl EguiScale for Spacing {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.button_padding.scale(scale);
-        self.combo_height.scale(scale);
-        self.combo_width.scale(scale);
-        self.icon_spacing.scale(scale);
-        self.icon_width.scale(scale);
-        self.icon_width_inner.scale(scale);
-        self.indent.scale(scale);
-        self.interact_size.scale(scale);
-        self.item_spacing.scale(scale);
-        self.menu_margin.scale(scale);
-        self.scroll.scale(scale);
-        self.slider_width.scale(scale);
-        self.text_edit_width.scale(scale);
-        self.tooltip_width.scale(scale);
-        self.window_margin.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
@@ -206,15 +260,7 @@ impl EguiScale for FontId {
 impl EguiScale for Style {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        if let Some(font_id) = &mut self.override_font_id {
-            font_id.scale(scale);
-        }
-        for font_id in self.text_styles.values_mut() {
-            font_id.scale(scale);
-        }
-        self.interaction.scale(scale);
-        self.spacing.scale(scale);
-        self.visuals.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
 
@@ -233,10 +279,6 @@ where
 impl EguiScale for Frame {
     #[inline]
     fn scale(&mut self, scale: f32) {
-        self.inner_margin.scale(scale);
-        self.outer_margin.scale(scale);
-        self.corner_radius.scale(scale);
-        self.shadow.scale(scale);
-        self.stroke.scale(scale);
+        self.scale_with(&ScaleFactors::uniform(scale));
     }
 }
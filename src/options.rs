@@ -0,0 +1,270 @@
+use egui::{epaint::Shadow, CornerRadius, FontId, Margin, Stroke, Vec2};
+
+use crate::{clamps::clamp, HairlinePolicy, RoundingPolicy, ScaleClamps, ScaleFactors};
+
+/// Largest number of points searched for one that spans whole physical pixels.
+const MAX_POINT_STEP: u8 = 16;
+
+/// Complete description of how style values are scaled.
+///
+/// Combines per-category [`ScaleFactors`] with policies
+/// that control how scaled values are stored.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ScaleOptions {
+    /// Per-category scale factors.
+    pub factors: ScaleFactors,
+
+    /// Rounding of integer-backed values like margins and corner radii.
+    pub rounding: RoundingPolicy,
+
+    /// Handling of strokes that become thin after scaling.
+    pub hairline: HairlinePolicy,
+
+    /// If set, scaled lengths in [`egui::style::Spacing`], [`Margin`]s, [`Stroke`] widths
+    /// and [`CornerRadius`] values are snapped to whole physical pixels
+    /// with this number of pixels per point, see [`egui::Context::pixels_per_point`].
+    ///
+    /// Integer-backed values can only hold whole points.
+    /// If a whole point does not span whole pixels, e.g. at 1.5 pixels per point,
+    /// they are rounded to the nearest number of points that does,
+    /// instead of being rounded according to the mode of [`ScaleOptions::rounding`].
+    ///
+    /// Values that are not finite and positive disable snapping.
+    pub snap_pixels_per_point: Option<f32>,
+
+    /// If set, hit targets never become smaller than this many points after scaling.
+    ///
+    /// Applies to [`egui::style::Spacing::interact_size`], [`egui::style::Spacing::icon_width`],
+    /// scroll bar widths in [`egui::style::ScrollStyle`] and resize grab radii
+    /// in [`egui::style::Interaction`], which are kept at no less than half of this value.
+    /// Text, strokes and other lengths are not affected.
+    pub min_touch_target: Option<f32>,
+
+    /// Per-category ranges that scaled values are clamped to.
+    pub clamps: ScaleClamps,
+}
+
+impl From<ScaleFactors> for ScaleOptions {
+    #[inline]
+    fn from(factors: ScaleFactors) -> Self {
+        ScaleOptions::new(factors)
+    }
+}
+
+impl ScaleOptions {
+    /// Returns options with the given factors and default policies.
+    #[inline]
+    #[must_use]
+    pub const fn new(factors: ScaleFactors) -> Self {
+        ScaleOptions {
+            factors,
+            rounding: RoundingPolicy::TRUNCATE,
+            hairline: HairlinePolicy::FADE,
+            snap_pixels_per_point: None,
+            min_touch_target: None,
+            clamps: ScaleClamps::NONE,
+        }
+    }
+
+    /// Returns options that scale all categories by the same factor.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(scale: f32) -> Self {
+        ScaleOptions::new(ScaleFactors::uniform(scale))
+    }
+
+    /// Returns these options with the given rounding policy.
+    #[inline]
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: RoundingPolicy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns these options with the given hairline policy.
+    #[inline]
+    #[must_use]
+    pub const fn with_hairline(mut self, hairline: HairlinePolicy) -> Self {
+        self.hairline = hairline;
+        self
+    }
+
+    /// Returns these options with scaled lengths snapped to whole physical pixels.
+    ///
+    /// Values that are not finite and positive disable snapping.
+    #[inline]
+    #[must_use]
+    pub const fn with_pixel_snapping(mut self, pixels_per_point: f32) -> Self {
+        self.snap_pixels_per_point = Some(pixels_per_point);
+        self
+    }
+
+    /// Returns these options with hit targets kept at least `points` large,
+    /// e.g. `44.0` for finger-sized targets on touch screens.
+    #[inline]
+    #[must_use]
+    pub const fn with_min_touch_target(mut self, points: f32) -> Self {
+        self.min_touch_target = Some(points);
+        self
+    }
+
+    /// Returns these options with scaled values clamped to the given ranges.
+    #[inline]
+    #[must_use]
+    pub const fn with_clamps(mut self, clamps: ScaleClamps) -> Self {
+        self.clamps = clamps;
+        self
+    }
+
+    /// Returns number of pixels per point to snap to, if snapping is enabled and valid.
+    #[inline]
+    fn pixels_per_point(&self) -> Option<f32> {
+        self.snap_pixels_per_point
+            .filter(|pixels_per_point| pixels_per_point.is_finite() && *pixels_per_point > 0.0)
+    }
+
+    /// Snaps a length in points to whole physical pixels if snapping is enabled.
+    #[inline]
+    fn snap(&self, value: f32) -> f32 {
+        match self.pixels_per_point() {
+            Some(pixels_per_point) => (value * pixels_per_point).round() / pixels_per_point,
+            None => value,
+        }
+    }
+
+    /// Rounds a snapped length to whole points that span whole physical pixels.
+    ///
+    /// Returns the length as is if every whole point spans whole pixels,
+    /// so that it is rounded according to the rounding policy.
+    #[inline]
+    fn snap_points(&self, value: f32, non_zero: bool) -> f32 {
+        let Some(pixels_per_point) = self.pixels_per_point() else {
+            return value;
+        };
+        let step = (1..=MAX_POINT_STEP).map(f32::from).find(|step| {
+            let pixels = step * pixels_per_point;
+            (pixels - pixels.round()).abs() < 1e-3
+        });
+        let Some(step) = step.filter(|step| *step > 1.0) else {
+            return value;
+        };
+
+        let steps = (value / step).round();
+        if steps == 0.0 && non_zero && self.rounding.keep_non_zero {
+            step.copysign(value)
+        } else {
+            steps * step
+        }
+    }
+
+    /// Scales a length with spacing factor.
+    #[inline]
+    pub(crate) fn length(&self, value: &mut f32) {
+        *value = clamp(
+            self.clamps.spacing,
+            self.snap(*value * self.factors.spacing),
+        );
+    }
+
+    /// Scales a size with spacing factor.
+    #[inline]
+    pub(crate) fn size(&self, value: &mut Vec2) {
+        self.length(&mut value.x);
+        self.length(&mut value.y);
+    }
+
+    /// Scales a widget width with spacing factor.
+    #[inline]
+    pub(crate) fn width(&self, value: &mut f32) {
+        *value = clamp(self.clamps.width, self.snap(*value * self.factors.spacing));
+    }
+
+    /// Scales a margin with spacing factor.
+    #[inline]
+    pub(crate) fn margin(&self, value: &mut Margin) {
+        for side in [
+            &mut value.left,
+            &mut value.right,
+            &mut value.top,
+            &mut value.bottom,
+        ] {
+            let scaled = self.snap(f32::from(*side) * self.factors.spacing);
+            let scaled = clamp(self.clamps.spacing, scaled);
+            let scaled = self.snap_points(scaled, *side != 0);
+            *side = self.rounding.round_i8(scaled, *side != 0);
+        }
+    }
+
+    /// Scales corner radius with corner radius factor.
+    #[inline]
+    pub(crate) fn corner_radius(&self, value: &mut CornerRadius) {
+        for corner in [&mut value.nw, &mut value.ne, &mut value.se, &mut value.sw] {
+            let scaled = self.snap(f32::from(*corner) * self.factors.corner_radius);
+            let scaled = clamp(self.clamps.corner_radius, scaled);
+            let scaled = self.snap_points(scaled, *corner != 0);
+            *corner = self.rounding.round_u8(scaled, *corner != 0);
+        }
+    }
+
+    /// Scales a shadow with shadow factor.
+    #[inline]
+    pub(crate) fn shadow(&self, value: &mut Shadow) {
+        for offset in &mut value.offset {
+            let scaled = f32::from(*offset) * self.factors.shadow;
+            let scaled = clamp(self.clamps.shadow, scaled.abs()).copysign(scaled);
+            *offset = self.rounding.round_i8(scaled, *offset != 0);
+        }
+        for length in [&mut value.blur, &mut value.spread] {
+            let scaled = clamp(self.clamps.shadow, f32::from(*length) * self.factors.shadow);
+            *length = self.rounding.round_u8(scaled, *length != 0);
+        }
+    }
+
+    /// Scales a stroke with stroke factor.
+    #[inline]
+    pub(crate) fn stroke(&self, value: &mut Stroke) {
+        if self.hairline == HairlinePolicy::Keep {
+            return;
+        }
+        self.hairline.scale_stroke(value, self.factors.stroke);
+        if value.width > 0.0 {
+            if let Some(pixels_per_point) = self.pixels_per_point() {
+                value.width = (value.width * pixels_per_point).round().max(1.0) / pixels_per_point;
+            }
+            value.width = clamp(self.clamps.stroke, value.width);
+        }
+    }
+
+    /// Scales a font with text factor.
+    #[inline]
+    pub(crate) fn font(&self, value: &mut FontId) {
+        value.size = clamp(self.clamps.text, value.size * self.factors.text);
+    }
+
+    /// Scales a hit-target length with interaction factor.
+    #[inline]
+    pub(crate) fn interaction(&self, value: &mut f32) {
+        *value = clamp(self.clamps.interaction, *value * self.factors.interaction);
+    }
+
+    /// Enlarges a scaled hit-target length to the minimum touch target.
+    #[inline]
+    pub(crate) fn touch_target(&self, value: &mut f32) {
+        if let Some(min) = self.min_touch_target {
+            *value = value.max(min);
+        }
+    }
+
+    /// Enlarges a scaled hit-target radius to half of the minimum touch target.
+    #[inline]
+    pub(crate) fn touch_radius(&self, value: &mut f32) {
+        if let Some(min) = self.min_touch_target {
+            *value = value.max(min * 0.5);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use egui::Style;
+
+use crate::{
+    EguiScaleWith, HairlinePolicy, RoundingPolicy, ScaleClamps, ScaleFactor, ScaleFactors,
+    ScaleOptions,
+};
+
+/// User display preferences for scaling, suitable for storing in a settings file.
+///
+/// With the `serde` feature enabled the profile can be saved and loaded
+/// with any serde format, like RON, JSON or TOML.
+/// Missing fields are filled with defaults.
+/// The global factor and per-category factors are validated on load,
+/// so NaN, infinite, zero and negative factors are rejected.
+/// Factors set in code are not validated, use [`ScaleFactor::new`] for them.
+///
+/// ```
+/// # use egui_scale::{ScaleFactor, ScaleProfile};
+/// let profile = ScaleProfile {
+///     scale: ScaleFactor::new(1.5).unwrap(),
+///     ..ScaleProfile::default()
+/// };
+/// let style = profile.applied(egui::Style::default());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ScaleProfile {
+    /// Global factor that multiplies every per-category factor.
+    pub scale: ScaleFactor,
+
+    /// Per-category factors relative to the global factor.
+    pub factors: ScaleFactors,
+
+    /// Per-category ranges that scaled values are clamped to.
+    pub clamps: ScaleClamps,
+
+    /// Rounding of integer-backed values like margins and corner radii.
+    pub rounding: RoundingPolicy,
+
+    /// Handling of strokes that become thin after scaling.
+    ///
+    /// Pixels per point of [`HairlinePolicy::MinPixels`] depend on the display
+    /// and are not serialized, see [`HairlinePolicy::with_pixels_per_point`].
+    pub hairline: HairlinePolicy,
+
+    /// Minimum size of hit targets in points, see [`ScaleOptions::min_touch_target`].
+    pub min_touch_target: Option<f32>,
+}
+
+impl ScaleProfile {
+    /// Returns scale options described by this profile.
+    ///
+    /// Pass them to [`EguiScaleContext::set_scale_options`](crate::EguiScaleContext::set_scale_options)
+    /// to apply the profile to both themes of a context.
+    #[must_use]
+    pub fn options(&self) -> ScaleOptions {
+        let scale = self.scale.get();
+        let factors = ScaleFactors {
+            text: self.factors.text * scale,
+            spacing: self.factors.spacing * scale,
+            stroke: self.factors.stroke * scale,
+            corner_radius: self.factors.corner_radius * scale,
+            shadow: self.factors.shadow * scale,
+            interaction: self.factors.interaction * scale,
+        };
+
+        ScaleOptions {
+            min_touch_target: self.min_touch_target,
+            ..ScaleOptions::new(factors)
+                .with_clamps(self.clamps)
+                .with_rounding(self.rounding)
+                .with_hairline(self.hairline)
+        }
+    }
+
+    /// Scales the unscaled style according to this profile.
+    #[inline]
+    pub fn apply(&self, style: &mut Style) {
+        style.scale_with_options(&self.options());
+    }
+
+    /// Scales the unscaled style according to this profile and returns it.
+    #[inline]
+    #[must_use]
+    pub fn applied(&self, mut style: Style) -> Style {
+        self.apply(&mut style);
+        style
+    }
+}
+
+impl From<ScaleProfile> for ScaleOptions {
+    #[inline]
+    fn from(profile: ScaleProfile) -> Self {
+        profile.options()
+    }
+}
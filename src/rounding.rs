@@ -0,0 +1,195 @@
+use egui::{epaint::Shadow, CornerRadius, Margin};
+
+/// How scaled values are rounded when stored in integer fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+    /// Round towards zero.
+    ///
+    /// This matches `as` casts and is the behavior of [`EguiScale`](crate::EguiScale).
+    #[default]
+    Truncate,
+
+    /// Round to the nearest integer, half-way cases away from zero.
+    Nearest,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round towards negative infinity.
+    Floor,
+}
+
+impl RoundingMode {
+    /// Rounds the value according to this mode.
+    #[inline]
+    #[must_use]
+    pub fn round(self, value: f32) -> f32 {
+        match self {
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::Nearest => value.round(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Floor => value.floor(),
+        }
+    }
+}
+
+/// Rounding policy for integer-backed values such as
+/// [`Margin`], [`CornerRadius`] and [`Shadow`] offsets, blur and spread.
+///
+/// Results outside of the integer range saturate to the closest representable value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct RoundingPolicy {
+    /// How scaled values are rounded.
+    pub mode: RoundingMode,
+
+    /// If set, non-zero values never become zero after scaling.
+    /// They are rounded away from zero to the smallest non-zero magnitude instead.
+    pub keep_non_zero: bool,
+}
+
+impl RoundingPolicy {
+    /// Truncating policy that allows non-zero values to become zero.
+    pub const TRUNCATE: Self = RoundingPolicy::new(RoundingMode::Truncate);
+
+    /// Rounding to nearest that allows non-zero values to become zero.
+    pub const NEAREST: Self = RoundingPolicy::new(RoundingMode::Nearest);
+
+    /// Returns policy with the given mode that allows non-zero values to become zero.
+    #[inline]
+    #[must_use]
+    pub const fn new(mode: RoundingMode) -> Self {
+        RoundingPolicy {
+            mode,
+            keep_non_zero: false,
+        }
+    }
+
+    /// Returns this policy with non-zero values never scaled to zero.
+    #[inline]
+    #[must_use]
+    pub const fn keep_non_zero(mut self) -> Self {
+        self.keep_non_zero = true;
+        self
+    }
+
+    /// Rounds a scaled value according to this policy.
+    ///
+    /// `non_zero` tells whether the value was non-zero before scaling.
+    #[inline]
+    #[must_use]
+    pub fn round(self, value: f32, non_zero: bool) -> f32 {
+        let rounded = self.mode.round(value);
+        if self.keep_non_zero && non_zero && rounded == 0.0 {
+            1.0f32.copysign(value)
+        } else {
+            rounded
+        }
+    }
+
+    /// Scales `u8` value with this policy.
+    #[inline]
+    #[must_use]
+    pub fn scale_u8(self, value: u8, scale: f32) -> u8 {
+        self.round_u8(f32::from(value) * scale, value != 0)
+    }
+
+    /// Scales `i8` value with this policy.
+    #[inline]
+    #[must_use]
+    pub fn scale_i8(self, value: i8, scale: f32) -> i8 {
+        self.round_i8(f32::from(value) * scale, value != 0)
+    }
+
+    /// Rounds a scaled value into `u8` with this policy.
+    #[inline]
+    pub(crate) fn round_u8(self, value: f32, non_zero: bool) -> u8 {
+        #![allow(clippy::cast_possible_truncation)]
+        #![allow(clippy::cast_sign_loss)]
+
+        self.round(value, non_zero) as u8
+    }
+
+    /// Rounds a scaled value into `i8` with this policy.
+    #[inline]
+    pub(crate) fn round_i8(self, value: f32, non_zero: bool) -> i8 {
+        #![allow(clippy::cast_possible_truncation)]
+
+        self.round(value, non_zero) as i8
+    }
+}
+
+/// A trait for scaling integer-backed values with explicit [`RoundingPolicy`].
+pub trait EguiScaleRounded {
+    /// Scales the value by the given factor, rounding according to the policy.
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy);
+
+    /// Scales the value by the given factor, rounding according to the policy,
+    /// and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_rounded(mut self, scale: f32, rounding: RoundingPolicy) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_rounded(scale, rounding);
+        self
+    }
+}
+
+impl EguiScaleRounded for u8 {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        *self = rounding.scale_u8(*self, scale);
+    }
+}
+
+impl EguiScaleRounded for i8 {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        *self = rounding.scale_i8(*self, scale);
+    }
+}
+
+impl<T: EguiScaleRounded> EguiScaleRounded for [T] {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        for value in self.iter_mut() {
+            value.scale_rounded(scale, rounding);
+        }
+    }
+}
+
+impl EguiScaleRounded for CornerRadius {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        self.nw.scale_rounded(scale, rounding);
+        self.ne.scale_rounded(scale, rounding);
+        self.se.scale_rounded(scale, rounding);
+        self.sw.scale_rounded(scale, rounding);
+    }
+}
+
+impl EguiScaleRounded for Margin {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        self.left.scale_rounded(scale, rounding);
+        self.right.scale_rounded(scale, rounding);
+        self.top.scale_rounded(scale, rounding);
+        self.bottom.scale_rounded(scale, rounding);
+    }
+}
+
+impl EguiScaleRounded for Shadow {
+    #[inline]
+    fn scale_rounded(&mut self, scale: f32, rounding: RoundingPolicy) {
+        self.offset.scale_rounded(scale, rounding);
+        self.blur.scale_rounded(scale, rounding);
+        self.spread.scale_rounded(scale, rounding);
+    }
+}
@@ -0,0 +1,152 @@
+use egui::Style;
+
+use crate::{EguiScaleWith, ScaleFactor, ScaleFactorError, ScaleFactors, ScaleOptions};
+
+/// Holds an unscaled base [`Style`] together with its scaled version.
+///
+/// The scaled style is always derived from the base,
+/// so changing the factor back and forth never accumulates rounding errors
+/// of integer-backed values like [`egui::Margin`] and [`egui::CornerRadius`].
+#[derive(Clone, Debug)]
+pub struct ScaledStyle {
+    base: Style,
+    options: ScaleOptions,
+    scaled: Style,
+}
+
+impl Default for ScaledStyle {
+    #[inline]
+    fn default() -> Self {
+        ScaledStyle::new(Style::default())
+    }
+}
+
+impl From<Style> for ScaledStyle {
+    #[inline]
+    fn from(base: Style) -> Self {
+        ScaledStyle::new(base)
+    }
+}
+
+impl ScaledStyle {
+    /// Creates a new scaled style with the given base and factor of `1.0`.
+    #[must_use]
+    pub fn new(base: Style) -> Self {
+        ScaledStyle {
+            scaled: base.clone(),
+            base,
+            options: ScaleOptions::default(),
+        }
+    }
+
+    /// Creates a new scaled style with the given base and factor.
+    #[must_use]
+    pub fn with_factor(base: Style, scale: f32) -> Self {
+        Self::with_factors(base, ScaleFactors::uniform(scale))
+    }
+
+    /// Creates a new scaled style with the given base and per-category factors.
+    #[must_use]
+    pub fn with_factors(base: Style, factors: ScaleFactors) -> Self {
+        Self::with_options(base, ScaleOptions::new(factors))
+    }
+
+    /// Creates a new scaled style with the given base and scale options.
+    #[must_use]
+    pub fn with_options(base: Style, options: ScaleOptions) -> Self {
+        ScaledStyle {
+            scaled: base.clone().scaled_with_options(&options),
+            base,
+            options,
+        }
+    }
+
+    /// Returns the unscaled base style.
+    #[inline]
+    #[must_use]
+    pub fn base(&self) -> &Style {
+        &self.base
+    }
+
+    /// Replaces the base style and rescales it with current factors.
+    pub fn set_base(&mut self, base: Style) {
+        self.base = base;
+        self.rescale();
+    }
+
+    /// Modifies the base style and rescales it with current factors.
+    pub fn modify_base(&mut self, f: impl FnOnce(&mut Style)) {
+        f(&mut self.base);
+        self.rescale();
+    }
+
+    /// Returns current per-category factors.
+    #[inline]
+    #[must_use]
+    pub fn factors(&self) -> &ScaleFactors {
+        &self.options.factors
+    }
+
+    /// Returns current scale options.
+    #[inline]
+    #[must_use]
+    pub fn options(&self) -> &ScaleOptions {
+        &self.options
+    }
+
+    /// Sets the same factor for all categories and rescales the base style.
+    #[inline]
+    pub fn set_factor(&mut self, scale: f32) {
+        self.set_factors(ScaleFactors::uniform(scale));
+    }
+
+    /// Validates the factor, sets it for all categories and rescales the base style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the style untouched
+    /// if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    pub fn try_set_factor(&mut self, scale: f32) -> Result<(), ScaleFactorError> {
+        self.set_factor(ScaleFactor::new(scale)?.get());
+        Ok(())
+    }
+
+    /// Sets per-category factors and rescales the base style.
+    pub fn set_factors(&mut self, factors: ScaleFactors) {
+        self.set_options(ScaleOptions {
+            factors,
+            ..self.options
+        });
+    }
+
+    /// Sets scale options and rescales the base style.
+    pub fn set_options(&mut self, options: ScaleOptions) {
+        if self.options != options {
+            self.options = options;
+            self.rescale();
+        }
+    }
+
+    /// Returns the base style scaled with current factors.
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> &Style {
+        &self.scaled
+    }
+
+    /// Returns the base style scaled by the given factor with current policies,
+    /// without changing current factors.
+    #[must_use]
+    pub fn scaled(&self, scale: f32) -> Style {
+        self.base.clone().scaled_with_options(&ScaleOptions {
+            factors: ScaleFactors::uniform(scale),
+            ..self.options
+        })
+    }
+
+    fn rescale(&mut self) {
+        self.scaled.clone_from(&self.base);
+        self.scaled.scale_with_options(&self.options);
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use egui::{
+    emath::TSTransform,
+    epaint::{
+        CircleShape, CubicBezierShape, EllipseShape, PathShape, QuadraticBezierShape, RectShape,
+        TextShape,
+    },
+    Pos2, Shape,
+};
+
+use crate::{EguiScale, EguiScaleAbout};
+
+/// Transform that scales about the origin.
+#[inline]
+fn transform_about(origin: Pos2, scale: f32) -> TSTransform {
+    TSTransform::new(origin.to_vec2() * (1.0 - scale), scale)
+}
+
+impl EguiScaleAbout for RectShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        self.rect.scale_about(origin, scale);
+        self.corner_radius.scale(scale);
+        self.stroke.scale(scale);
+        self.blur_width.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for CircleShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        self.center.scale_about(origin, scale);
+        self.radius.scale(scale);
+        self.stroke.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for EllipseShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        self.center.scale_about(origin, scale);
+        self.radius.scale(scale);
+        self.stroke.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for PathShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        for point in &mut self.points {
+            point.scale_about(origin, scale);
+        }
+        self.stroke.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for CubicBezierShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        for point in &mut self.points {
+            point.scale_about(origin, scale);
+        }
+        self.stroke.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for QuadraticBezierShape {
+    type Origin = Pos2;
+
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        for point in &mut self.points {
+            point.scale_about(origin, scale);
+        }
+        self.stroke.scale(scale);
+    }
+}
+
+impl EguiScaleAbout for TextShape {
+    type Origin = Pos2;
+
+    /// Scales position and laid out galley of the text.
+    ///
+    /// Glyphs are scaled as already rasterized,
+    /// lay the text out again with scaled fonts for the sharpest result.
+    #[inline]
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        let underline = self.underline;
+        self.transform(transform_about(origin, scale));
+        self.underline = underline.scaled(scale);
+    }
+}
+
+impl EguiScaleAbout for Shape {
+    type Origin = Pos2;
+
+    fn scale_about(&mut self, origin: Pos2, scale: f32) {
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    shape.scale_about(origin, scale);
+                }
+            }
+            Shape::Circle(shape) => shape.scale_about(origin, scale),
+            Shape::Ellipse(shape) => shape.scale_about(origin, scale),
+            Shape::LineSegment { points, stroke } => {
+                for point in points {
+                    point.scale_about(origin, scale);
+                }
+                stroke.scale(scale);
+            }
+            Shape::Path(shape) => shape.scale_about(origin, scale),
+            Shape::Rect(shape) => shape.scale_about(origin, scale),
+            Shape::Text(shape) => shape.scale_about(origin, scale),
+            Shape::Mesh(mesh) => Arc::make_mut(mesh).transform(transform_about(origin, scale)),
+            Shape::QuadraticBezier(shape) => shape.scale_about(origin, scale),
+            Shape::CubicBezier(shape) => shape.scale_about(origin, scale),
+            Shape::Callback(callback) => {
+                callback.rect.scale_about(origin, scale);
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use egui::{
+    epaint::{ColorMode, PathStroke},
+    Color32, Stroke,
+};
+
+/// Color space in which stroke color is faded when a stroke is clamped to a minimum width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FadeSpace {
+    /// Multiply color in gamma space, see [`egui::Color32::gamma_multiply`].
+    #[default]
+    Gamma,
+
+    /// Multiply color in linear space, see [`egui::Color32::linear_multiply`].
+    Linear,
+}
+
+/// Controls what happens to strokes that become thin after scaling.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HairlinePolicy {
+    /// Strokes never become thinner than `min_width` points.
+    /// Instead their color is faded proportionally to keep the perceived weight.
+    ///
+    /// Strokes that were already thinner than `min_width` before scaling
+    /// are not made thinner than they were.
+    Fade {
+        /// Minimum stroke width in points.
+        min_width: f32,
+
+        /// Color space in which the color is faded.
+        space: FadeSpace,
+    },
+
+    /// Scale stroke widths as is, allowing sub-point widths.
+    Thin,
+
+    /// Strokes never become thinner than `min_pixels` physical pixels.
+    ///
+    /// Strokes that were already thinner than that before scaling
+    /// are not made thinner than they were.
+    MinPixels {
+        /// Minimum stroke width in physical pixels.
+        min_pixels: f32,
+
+        /// Number of physical pixels per point, see [`egui::Context::pixels_per_point`].
+        ///
+        /// It depends on the display, so it is not serialized.
+        /// Deserialized policies use `1.0` until updated with
+        /// [`HairlinePolicy::with_pixels_per_point`].
+        #[cfg_attr(feature = "serde", serde(skip, default = "one"))]
+        pixels_per_point: f32,
+    },
+
+    /// Leave strokes untouched.
+    Keep,
+}
+
+/// Default number of physical pixels per point of deserialized policies.
+#[cfg(feature = "serde")]
+#[inline]
+const fn one() -> f32 {
+    1.0
+}
+
+impl Default for HairlinePolicy {
+    #[inline]
+    fn default() -> Self {
+        HairlinePolicy::FADE
+    }
+}
+
+impl HairlinePolicy {
+    /// Fade strokes thinner than one point in gamma space.
+    ///
+    /// This is the behavior of [`EguiScale`](crate::EguiScale) for [`Stroke`].
+    pub const FADE: Self = HairlinePolicy::Fade {
+        min_width: 1.0,
+        space: FadeSpace::Gamma,
+    };
+
+    /// Returns this policy with the given number of physical pixels per point.
+    ///
+    /// Only [`HairlinePolicy::MinPixels`] depends on it, other policies are returned as is.
+    #[inline]
+    #[must_use]
+    pub fn with_pixels_per_point(self, pixels_per_point: f32) -> Self {
+        match self {
+            HairlinePolicy::MinPixels { min_pixels, .. } => HairlinePolicy::MinPixels {
+                min_pixels,
+                pixels_per_point,
+            },
+            policy => policy,
+        }
+    }
+
+    /// Scales the stroke by the given factor according to this policy.
+    pub fn scale_stroke(self, stroke: &mut Stroke, scale: f32) {
+        let width = stroke.width * scale;
+
+        match self {
+            HairlinePolicy::Fade { min_width, space } => {
+                let min_width = min_width.min(stroke.width);
+                if width < min_width {
+                    let fade = width.max(0.0) / min_width;
+                    stroke.color = match space {
+                        FadeSpace::Gamma => stroke.color.gamma_multiply(fade),
+                        FadeSpace::Linear => stroke.color.linear_multiply(fade),
+                    };
+                    stroke.width = min_width;
+                } else {
+                    stroke.width = width;
+                }
+            }
+            HairlinePolicy::Thin => stroke.width = width,
+            HairlinePolicy::MinPixels {
+                min_pixels,
+                pixels_per_point,
+            } => {
+                let min_width = (min_pixels / pixels_per_point).min(stroke.width);
+                stroke.width = width.max(min_width);
+            }
+            HairlinePolicy::Keep => {}
+        }
+    }
+
+    /// Scales the path stroke by the given factor according to this policy.
+    ///
+    /// Colors of [`ColorMode::UV`] strokes are computed by a callback and cannot be faded,
+    /// so only their width is affected.
+    pub fn scale_path_stroke(self, stroke: &mut PathStroke, scale: f32) {
+        let color = match stroke.color {
+            ColorMode::Solid(color) => color,
+            ColorMode::UV(_) => Color32::TRANSPARENT,
+        };
+
+        let mut solid = Stroke::new(stroke.width, color);
+        self.scale_stroke(&mut solid, scale);
+
+        stroke.width = solid.width;
+        if let ColorMode::Solid(color) = &mut stroke.color {
+            *color = solid.color;
+        }
+    }
+}
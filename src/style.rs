@@ -0,0 +1,168 @@
+use egui::{
+    style::{Interaction, ScrollStyle, Spacing, TextCursorStyle, WidgetVisuals, Widgets},
+    Frame, Style, Visuals,
+};
+
+use crate::{EguiScale, ScaleFactors, ScaleOptions};
+
+/// A trait for scaling style types with per-category [`ScaleFactors`]
+/// or complete [`ScaleOptions`].
+pub trait EguiScaleWith {
+    /// Scales the value according to the given options.
+    fn scale_with_options(&mut self, options: &ScaleOptions);
+
+    /// Scales the value according to the given options and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_with_options(mut self, options: &ScaleOptions) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_with_options(options);
+        self
+    }
+
+    /// Scales the value with the given factors.
+    #[inline]
+    fn scale_with(&mut self, factors: &ScaleFactors) {
+        self.scale_with_options(&ScaleOptions::new(*factors));
+    }
+
+    /// Scales the value with the given factors and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_with(mut self, factors: &ScaleFactors) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_with(factors);
+        self
+    }
+}
+
+impl EguiScaleWith for WidgetVisuals {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.stroke(&mut self.bg_stroke);
+        options.corner_radius(&mut self.corner_radius);
+        options.stroke(&mut self.fg_stroke);
+        options.length(&mut self.expansion);
+    }
+}
+
+impl EguiScaleWith for Interaction {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.interaction(&mut self.interact_radius);
+        options.interaction(&mut self.resize_grab_radius_corner);
+        options.interaction(&mut self.resize_grab_radius_side);
+        options.touch_radius(&mut self.resize_grab_radius_corner);
+        options.touch_radius(&mut self.resize_grab_radius_side);
+    }
+}
+
+impl EguiScaleWith for Widgets {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        self.noninteractive.scale_with_options(options);
+        self.inactive.scale_with_options(options);
+        self.hovered.scale_with_options(options);
+        self.active.scale_with_options(options);
+        self.open.scale_with_options(options);
+    }
+}
+
+impl EguiScaleWith for TextCursorStyle {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.stroke(&mut self.stroke);
+    }
+}
+
+impl EguiScaleWith for Visuals {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.length(&mut self.clip_rect_margin);
+        options.corner_radius(&mut self.menu_corner_radius);
+        options.shadow(&mut self.popup_shadow);
+        options.interaction(&mut self.resize_corner_size);
+        options.stroke(&mut self.selection.stroke);
+        self.text_cursor.scale_with_options(options);
+        self.widgets.scale_with_options(options);
+        options.corner_radius(&mut self.window_corner_radius);
+        options.shadow(&mut self.window_shadow);
+        options.stroke(&mut self.window_stroke);
+    }
+}
+
+impl EguiScaleWith for ScrollStyle {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.length(&mut self.bar_inner_margin);
+        options.length(&mut self.bar_outer_margin);
+        options.length(&mut self.bar_width);
+        options.length(&mut self.floating_allocated_width);
+        options.length(&mut self.floating_width);
+        options.length(&mut self.handle_min_length);
+        options.touch_target(&mut self.bar_width);
+        options.touch_target(&mut self.floating_width);
+    }
+}
+
+impl EguiScaleWith for Spacing {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.size(&mut self.button_padding);
+        options.length(&mut self.combo_height);
+        options.width(&mut self.combo_width);
+        options.width(&mut self.default_area_size.x);
+        options.width(&mut self.default_area_size.y);
+        options.length(&mut self.icon_spacing);
+        options.length(&mut self.icon_width);
+        options.length(&mut self.icon_width_inner);
+        options.length(&mut self.indent);
+        options.size(&mut self.interact_size);
+        options.size(&mut self.item_spacing);
+        options.margin(&mut self.menu_margin);
+        options.length(&mut self.menu_spacing);
+        options.width(&mut self.menu_width);
+        self.scroll.scale_with_options(options);
+        options.length(&mut self.slider_rail_height);
+        options.width(&mut self.slider_width);
+        options.width(&mut self.text_edit_width);
+        options.width(&mut self.tooltip_width);
+        options.margin(&mut self.window_margin);
+        options.touch_target(&mut self.icon_width);
+        options.touch_target(&mut self.interact_size.x);
+        options.touch_target(&mut self.interact_size.y);
+    }
+}
+
+impl EguiScaleWith for Style {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        if let Some(font_id) = &mut self.override_font_id {
+            options.font(font_id);
+        }
+        for font_id in self.text_styles.values_mut() {
+            options.font(font_id);
+        }
+        self.interaction.scale_with_options(options);
+        self.scroll_animation
+            .points_per_second
+            .scale(options.factors.spacing);
+        self.spacing.scale_with_options(options);
+        self.visuals.scale_with_options(options);
+    }
+}
+
+impl EguiScaleWith for Frame {
+    #[inline]
+    fn scale_with_options(&mut self, options: &ScaleOptions) {
+        options.margin(&mut self.inner_margin);
+        options.margin(&mut self.outer_margin);
+        options.corner_radius(&mut self.corner_radius);
+        options.shadow(&mut self.shadow);
+        options.stroke(&mut self.stroke);
+    }
+}
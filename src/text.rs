@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use egui::{
+    text::{LayoutJob, LayoutSection, TextFormat, TextWrapping},
+    Align, FontSelection, RichText, Style, WidgetText,
+};
+
+use crate::EguiScale;
+
+impl EguiScale for TextFormat {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        self.font_id.scale(scale);
+        self.extra_letter_spacing.scale(scale);
+        self.line_height.scale(scale);
+        self.expand_bg.scale(scale);
+        self.underline.scale(scale);
+        self.strikethrough.scale(scale);
+    }
+}
+
+impl EguiScale for LayoutSection {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        self.leading_space.scale(scale);
+        self.format.scale(scale);
+    }
+}
+
+impl EguiScale for TextWrapping {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        self.max_width.scale(scale);
+    }
+}
+
+impl EguiScale for LayoutJob {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        self.sections.scale(scale);
+        self.wrap.scale(scale);
+        self.first_row_min_height.scale(scale);
+    }
+}
+
+/// A trait for scaling text that keeps its formatting private,
+/// like [`RichText`] and [`WidgetText`].
+///
+/// egui does not expose explicit sizes stored in such text,
+/// so it is converted into a [`LayoutJob`] which is then scaled.
+pub trait EguiScaleText {
+    /// Converts the text into a [`LayoutJob`] and scales it by the given factor.
+    ///
+    /// Formatting that is not set explicitly is taken from the style,
+    /// so pass an unscaled style to avoid scaling those values twice.
+    #[must_use]
+    fn into_scaled_layout_job(self, style: &Style, scale: f32) -> LayoutJob;
+}
+
+impl EguiScaleText for RichText {
+    fn into_scaled_layout_job(self, style: &Style, scale: f32) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        self.append_to(&mut job, style, FontSelection::Default, Align::Center);
+        job.scaled(scale)
+    }
+}
+
+impl EguiScaleText for WidgetText {
+    fn into_scaled_layout_job(self, style: &Style, scale: f32) -> LayoutJob {
+        let job = self.into_layout_job(style, FontSelection::Default, Align::Center);
+        Arc::unwrap_or_clone(job).scaled(scale)
+    }
+}
@@ -0,0 +1,79 @@
+use egui::{
+    style::{Interaction, ScrollAnimation, TextCursorStyle},
+    Style, Visuals,
+};
+
+/// A trait for scaling durations, like [`EguiScale`](crate::EguiScale) does for lengths.
+///
+/// Factors above `1.0` slow motion down and factors below `1.0` speed it up.
+/// A factor of `0.0` makes animations instant, which suits a "reduce motion" preference.
+pub trait EguiScaleTime {
+    /// Scales durations by the given factor.
+    fn scale_time(&mut self, scale: f32);
+
+    /// Scales durations by the given factor and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_time(mut self, scale: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_time(scale);
+        self
+    }
+}
+
+impl EguiScaleTime for f32 {
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        *self *= scale;
+    }
+}
+
+impl EguiScaleTime for ScrollAnimation {
+    /// Scales duration range and divides scroll speed by the factor.
+    ///
+    /// Scroll speed is kept if the factor is not finite and positive,
+    /// since zero durations already make scrolling instant.
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        if scale.is_finite() && scale > 0.0 {
+            self.points_per_second /= scale;
+        }
+        self.duration.min.scale_time(scale);
+        self.duration.max.scale_time(scale);
+    }
+}
+
+impl EguiScaleTime for Interaction {
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        self.tooltip_delay.scale_time(scale);
+        self.tooltip_grace_time.scale_time(scale);
+    }
+}
+
+impl EguiScaleTime for TextCursorStyle {
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        self.on_duration.scale_time(scale);
+        self.off_duration.scale_time(scale);
+    }
+}
+
+impl EguiScaleTime for Visuals {
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        self.text_cursor.scale_time(scale);
+    }
+}
+
+impl EguiScaleTime for Style {
+    #[inline]
+    fn scale_time(&mut self, scale: f32) {
+        self.animation_time.scale_time(scale);
+        self.interaction.scale_time(scale);
+        self.scroll_animation.scale_time(scale);
+        self.visuals.scale_time(scale);
+    }
+}
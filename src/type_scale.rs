@@ -0,0 +1,119 @@
+use egui::{Style, TextStyle};
+
+use crate::EguiScale;
+
+/// Modular typographic scale that derives font sizes from a body size and a ratio.
+///
+/// Each text style is assigned a step on the scale and its size becomes
+/// `body * ratio.powi(step)`. [`TextStyle::Body`], [`TextStyle::Button`] and
+/// [`TextStyle::Monospace`] are at step `0`, [`TextStyle::Small`] at step `-1`
+/// and [`TextStyle::Heading`] at step `2`.
+///
+/// Named styles are placed on the step closest to their current size
+/// relative to the current body size.
+///
+/// The generated sizes are unscaled, scale the style with [`EguiScale`] afterwards.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TypeScale {
+    /// Font size of [`TextStyle::Body`] in points.
+    pub body: f32,
+
+    /// Ratio between sizes of consecutive steps.
+    pub ratio: f32,
+}
+
+impl Default for TypeScale {
+    #[inline]
+    fn default() -> Self {
+        Self::new(12.5, Self::MAJOR_THIRD)
+    }
+}
+
+impl TypeScale {
+    /// Ratio of a minor third, `1.2`.
+    pub const MINOR_THIRD: f32 = 1.2;
+
+    /// Ratio of a major third, `1.25`.
+    pub const MAJOR_THIRD: f32 = 1.25;
+
+    /// Ratio of a perfect fourth, `1.333`.
+    pub const PERFECT_FOURTH: f32 = 4.0 / 3.0;
+
+    /// Ratio of a perfect fifth, `1.5`.
+    pub const PERFECT_FIFTH: f32 = 1.5;
+
+    /// Ratio of the golden section, `1.618`.
+    pub const GOLDEN_RATIO: f32 = 1.618_034;
+
+    /// Returns type scale with the given body size and ratio.
+    #[inline]
+    #[must_use]
+    pub const fn new(body: f32, ratio: f32) -> Self {
+        TypeScale { body, ratio }
+    }
+
+    /// Returns font size at the given step of the scale.
+    #[inline]
+    #[must_use]
+    pub fn size(&self, step: i32) -> f32 {
+        self.body * self.ratio.powi(step)
+    }
+
+    /// Returns step of the built-in text style,
+    /// or `None` for [`TextStyle::Name`].
+    #[inline]
+    #[must_use]
+    pub fn step(text_style: &TextStyle) -> Option<i32> {
+        match text_style {
+            TextStyle::Small => Some(-1),
+            TextStyle::Body | TextStyle::Button | TextStyle::Monospace => Some(0),
+            TextStyle::Heading => Some(2),
+            TextStyle::Name(_) => None,
+        }
+    }
+
+    /// Rebuilds sizes of [`Style::text_styles`] from this scale.
+    ///
+    /// Font families are kept as is.
+    pub fn apply(&self, style: &mut Style) {
+        #![allow(clippy::cast_possible_truncation)]
+
+        let old_body = style
+            .text_styles
+            .get(&TextStyle::Body)
+            .map_or(self.body, |font_id| font_id.size);
+
+        for (text_style, font_id) in &mut style.text_styles {
+            let step = Self::step(text_style).unwrap_or_else(|| {
+                let step = (font_id.size / old_body).ln() / self.ratio.ln();
+                if step.is_finite() {
+                    step.round() as i32
+                } else {
+                    0
+                }
+            });
+            font_id.size = self.size(step);
+        }
+    }
+
+    /// Rebuilds sizes of [`Style::text_styles`] from this scale
+    /// and returns the modified style.
+    #[inline]
+    #[must_use]
+    pub fn applied(&self, mut style: Style) -> Style {
+        self.apply(&mut style);
+        style
+    }
+}
+
+impl EguiScale for TypeScale {
+    #[inline]
+    fn scale(&mut self, scale: f32) {
+        self.body.scale(scale);
+    }
+}
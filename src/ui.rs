@@ -0,0 +1,74 @@
+use egui::{InnerResponse, Ui, UiBuilder, UiStackInfo};
+
+use crate::{
+    EguiScaleContext, EguiScaleWith, ScaleFactor, ScaleFactorError, ScaleFactors, ScaleOptions,
+};
+
+/// Key of the [`egui::UiTags`] entry that records the factor of a scaled scope.
+const SCALE_TAG: &str = "egui_scale";
+
+/// Extension trait for [`Ui`] that scales styles of child scopes.
+pub trait EguiScaleUi {
+    /// Adds a child [`Ui`] with style scaled by the given factor.
+    ///
+    /// The style of this `Ui` is left untouched.
+    /// Nested scopes multiply their factors.
+    /// Rounding, hairline and snapping policies are taken from context scale options,
+    /// see [`EguiScaleContext::scale_options`].
+    fn scaled_scope<R>(
+        &mut self,
+        scale: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R>;
+
+    /// Validates the factor and adds a child [`Ui`] with style scaled by it,
+    /// see [`EguiScaleUi::scaled_scope`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without adding the child `Ui`
+    /// if the factor is NaN, infinite, zero or negative.
+    #[inline]
+    fn try_scaled_scope<R>(
+        &mut self,
+        scale: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<InnerResponse<R>, ScaleFactorError> {
+        Ok(self.scaled_scope(ScaleFactor::new(scale)?.get(), add_contents))
+    }
+
+    /// Returns the scale factor in effect for this [`Ui`].
+    ///
+    /// This is the context scale factor, see [`EguiScaleContext::scale_factor`],
+    /// multiplied by factors of all enclosing [`EguiScaleUi::scaled_scope`]s.
+    /// Custom widgets can use it to size painted shapes, icons and images
+    /// consistently with the scaled style.
+    fn effective_scale(&self) -> f32;
+}
+
+impl EguiScaleUi for Ui {
+    fn scaled_scope<R>(
+        &mut self,
+        scale: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let options = ScaleOptions {
+            factors: ScaleFactors::uniform(scale),
+            ..self.ctx().scale_options()
+        };
+
+        let builder = UiBuilder::new()
+            .style(self.style().as_ref().clone().scaled_with_options(&options))
+            .ui_stack_info(UiStackInfo::default().with_tag_value(SCALE_TAG, scale));
+
+        self.scope_builder(builder, add_contents)
+    }
+
+    fn effective_scale(&self) -> f32 {
+        self.stack()
+            .iter()
+            .filter_map(|stack| stack.tags().get_downcast::<f32>(SCALE_TAG))
+            .product::<f32>()
+            * self.ctx().scale_factor()
+    }
+}
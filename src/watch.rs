@@ -0,0 +1,187 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use egui::Context;
+
+use crate::{EguiScaleContext, ScaleOptions, ScaleProfile};
+
+/// Error type returned by profile parse functions.
+pub type ProfileParseError = Box<dyn Error + Send + Sync>;
+
+/// Function that parses contents of a profile file.
+type ParseFn = dyn Fn(&str) -> Result<ScaleProfile, ProfileParseError> + Send + Sync;
+
+/// Watches a [`ScaleProfile`] file and reapplies it to a context when the file changes.
+///
+/// The file is parsed with a user-provided function, so any format can be used,
+/// e.g. `|text| Ok(serde_json::from_str(text)?)` with the `serde` feature enabled,
+/// or a file with just the global factor:
+///
+/// ```no_run
+/// # use egui_scale::{ProfileWatcher, ScaleFactor, ScaleProfile};
+/// # let ctx = egui::Context::default();
+/// let mut watcher = ProfileWatcher::new("scale.txt", |text| {
+///     Ok(ScaleProfile {
+///         scale: ScaleFactor::new(text.trim().parse()?)?,
+///         ..ScaleProfile::default()
+///     })
+/// });
+///
+/// // Every frame:
+/// if let Err(err) = watcher.update(&ctx) {
+///     eprintln!("{err}");
+/// }
+/// ```
+///
+/// Profiles are applied with [`EguiScaleContext::set_scale_options`],
+/// which derives styles from the unscaled ones, so reloads never compound.
+/// Pixel snapping of the context is kept, and [`HairlinePolicy::MinPixels`](crate::HairlinePolicy::MinPixels)
+/// uses pixels per point of the context.
+pub struct ProfileWatcher {
+    path: PathBuf,
+    parse: Box<ParseFn>,
+    interval: Duration,
+    next_check: f64,
+    modified: Option<SystemTime>,
+    missing: bool,
+}
+
+impl fmt::Debug for ProfileWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProfileWatcher")
+            .field("path", &self.path)
+            .field("interval", &self.interval)
+            .field("next_check", &self.next_check)
+            .field("modified", &self.modified)
+            .field("missing", &self.missing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ProfileWatcher {
+    /// Default interval between checks of the file.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Returns watcher for the file at the given path.
+    ///
+    /// The profile is loaded on the first [`ProfileWatcher::update`].
+    #[must_use]
+    pub fn new(
+        path: impl Into<PathBuf>,
+        parse: impl Fn(&str) -> Result<ScaleProfile, ProfileParseError> + Send + Sync + 'static,
+    ) -> Self {
+        ProfileWatcher {
+            path: path.into(),
+            parse: Box::new(parse),
+            interval: Self::DEFAULT_INTERVAL,
+            next_check: f64::NEG_INFINITY,
+            modified: None,
+            missing: false,
+        }
+    }
+
+    /// Returns this watcher with the given interval between checks of the file.
+    #[inline]
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns path of the watched file.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads and parses the watched file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load(&self) -> Result<ScaleProfile, ProfileWatchError> {
+        let text = fs::read_to_string(&self.path).map_err(ProfileWatchError::Io)?;
+        (self.parse)(&text).map_err(ProfileWatchError::Parse)
+    }
+
+    /// Checks the file and applies the profile to the context if the file changed.
+    ///
+    /// Call it once per frame, the file is checked at most once per interval.
+    /// Returns `true` if the profile was reloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    /// Each error is reported once: the previously applied profile stays in effect,
+    /// and the file is loaded again once it changes or reappears.
+    pub fn update(&mut self, ctx: &Context) -> Result<bool, ProfileWatchError> {
+        ctx.request_repaint_after(self.interval);
+
+        let now = ctx.input(|input| input.time);
+        if now < self.next_check {
+            return Ok(false);
+        }
+        self.next_check = now + self.interval.as_secs_f64();
+
+        let modified = match fs::metadata(&self.path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => modified,
+            Err(_) if self.missing => return Ok(false),
+            Err(err) => {
+                self.missing = true;
+                self.modified = None;
+                return Err(ProfileWatchError::Io(err));
+            }
+        };
+        self.missing = false;
+        if self.modified == Some(modified) {
+            return Ok(false);
+        }
+
+        // Remember the change even if loading fails, so a broken file is reported once.
+        self.modified = Some(modified);
+        let profile = self.load()?;
+        let current = ctx.scale_options();
+        let options = profile.options();
+        ctx.set_scale_options(ScaleOptions {
+            hairline: options
+                .hairline
+                .with_pixels_per_point(ctx.pixels_per_point()),
+            snap_pixels_per_point: current.snap_pixels_per_point,
+            ..options
+        });
+        Ok(true)
+    }
+}
+
+/// Error returned when a watched profile file cannot be loaded.
+#[derive(Debug)]
+pub enum ProfileWatchError {
+    /// The file cannot be read.
+    Io(io::Error),
+
+    /// The file cannot be parsed.
+    Parse(ProfileParseError),
+}
+
+impl fmt::Display for ProfileWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileWatchError::Io(err) => write!(f, "failed to read scale profile: {err}"),
+            ProfileWatchError::Parse(err) => write!(f, "failed to parse scale profile: {err}"),
+        }
+    }
+}
+
+impl Error for ProfileWatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProfileWatchError::Io(err) => Some(err),
+            ProfileWatchError::Parse(err) => Some(&**err),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use egui::{
+    epaint::Shadow,
+    style::{Interaction, ScrollStyle, Spacing, TextCursorStyle, WidgetVisuals, Widgets},
+    CornerRadius, FontId, Frame, Margin, Stroke, Style, Vec2, Visuals,
+};
+
+use crate::EguiScale;
+
+/// A trait for scaling horizontal and vertical lengths by different factors.
+///
+/// Horizontal lengths are scaled by `scale.x` and vertical lengths by `scale.y`.
+/// Lengths without a direction, such as font sizes, stroke widths, corner radii
+/// and sizes of square icons, are scaled by the geometric mean of the two factors.
+pub trait EguiScaleXY {
+    /// Scales the value by the given horizontal and vertical factors.
+    fn scale_xy(&mut self, scale: Vec2);
+
+    /// Scales the value by the given horizontal and vertical factors
+    /// and return the modified value.
+    #[inline]
+    #[must_use]
+    fn scaled_xy(mut self, scale: Vec2) -> Self
+    where
+        Self: Sized,
+    {
+        self.scale_xy(scale);
+        self
+    }
+}
+
+/// Factor for lengths without a direction.
+#[inline]
+fn mean(scale: Vec2) -> f32 {
+    (scale.x * scale.y).sqrt()
+}
+
+impl EguiScaleXY for Vec2 {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        *self = *self * scale;
+    }
+}
+
+impl EguiScaleXY for Margin {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.left.scale(scale.x);
+        self.right.scale(scale.x);
+        self.top.scale(scale.y);
+        self.bottom.scale(scale.y);
+    }
+}
+
+impl EguiScaleXY for CornerRadius {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Stroke {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for FontId {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Shadow {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.offset[0].scale(scale.x);
+        self.offset[1].scale(scale.y);
+        self.blur.scale(mean(scale));
+        self.spread.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for WidgetVisuals {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Interaction {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Widgets {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for TextCursorStyle {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Visuals {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.clip_rect_margin.scale(mean(scale));
+        self.menu_corner_radius.scale_xy(scale);
+        self.popup_shadow.scale_xy(scale);
+        self.resize_corner_size.scale(mean(scale));
+        self.selection.stroke.scale_xy(scale);
+        self.text_cursor.scale_xy(scale);
+        self.widgets.scale_xy(scale);
+        self.window_corner_radius.scale_xy(scale);
+        self.window_shadow.scale_xy(scale);
+        self.window_stroke.scale_xy(scale);
+    }
+}
+
+impl EguiScaleXY for ScrollStyle {
+    /// Scroll bars are laid out both horizontally and vertically,
+    /// so their lengths are scaled by the mean factor.
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.scale(mean(scale));
+    }
+}
+
+impl EguiScaleXY for Spacing {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.button_padding.scale_xy(scale);
+        self.combo_height.scale(scale.y);
+        self.combo_width.scale(scale.x);
+        self.default_area_size.scale_xy(scale);
+        self.icon_spacing.scale(scale.x);
+        self.icon_width.scale(mean(scale));
+        self.icon_width_inner.scale(mean(scale));
+        self.indent.scale(scale.x);
+        self.interact_size.scale_xy(scale);
+        self.item_spacing.scale_xy(scale);
+        self.menu_margin.scale_xy(scale);
+        self.menu_spacing.scale(scale.x);
+        self.menu_width.scale(scale.x);
+        self.scroll.scale_xy(scale);
+        self.slider_rail_height.scale(scale.y);
+        self.slider_width.scale(scale.x);
+        self.text_edit_width.scale(scale.x);
+        self.tooltip_width.scale(scale.x);
+        self.window_margin.scale_xy(scale);
+    }
+}
+
+impl EguiScaleXY for Style {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        if let Some(font_id) = &mut self.override_font_id {
+            font_id.scale_xy(scale);
+        }
+        for font_id in self.text_styles.values_mut() {
+            font_id.scale_xy(scale);
+        }
+        self.interaction.scale_xy(scale);
+        self.scroll_animation.points_per_second.scale(mean(scale));
+        self.spacing.scale_xy(scale);
+        self.visuals.scale_xy(scale);
+    }
+}
+
+impl EguiScaleXY for Frame {
+    #[inline]
+    fn scale_xy(&mut self, scale: Vec2) {
+        self.inner_margin.scale_xy(scale);
+        self.outer_margin.scale_xy(scale);
+        self.corner_radius.scale_xy(scale);
+        self.shadow.scale_xy(scale);
+        self.stroke.scale_xy(scale);
+    }
+}
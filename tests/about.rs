@@ -0,0 +1,51 @@
+//! Checks scaling of geometry about a non-zero origin.
+
+use egui::{pos2, vec2, Pos2, Rangef, Rect};
+use egui_scale::EguiScaleAbout;
+
+#[test]
+fn pos2_about_origin() {
+    let origin = pos2(10.0, 20.0);
+    assert_eq!(pos2(12.0, 25.0).scaled_about(origin, 2.0), pos2(14.0, 30.0));
+    assert_eq!(pos2(6.0, 16.0).scaled_about(origin, 0.5), pos2(8.0, 18.0));
+    assert_eq!(origin.scaled_about(origin, 3.0), origin);
+}
+
+#[test]
+fn vec2_about_origin() {
+    assert_eq!(
+        vec2(12.0, 25.0).scaled_about(vec2(10.0, 20.0), 2.0),
+        vec2(14.0, 30.0)
+    );
+}
+
+#[test]
+fn rect_about_center_and_corner() {
+    let rect = Rect::from_min_max(pos2(10.0, 20.0), pos2(30.0, 30.0));
+
+    let scaled = rect.scaled_about(rect.center(), 2.0);
+    assert_eq!(scaled.center(), rect.center());
+    assert_eq!(
+        scaled,
+        Rect::from_min_max(pos2(0.0, 15.0), pos2(40.0, 35.0))
+    );
+
+    let scaled = rect.scaled_about(rect.min, 0.5);
+    assert_eq!(
+        scaled,
+        Rect::from_min_max(pos2(10.0, 20.0), pos2(20.0, 25.0))
+    );
+
+    let origin = Pos2::new(-10.0, 40.0);
+    assert_eq!(
+        rect.scaled_about(origin, 2.0),
+        Rect::from_min_max(pos2(30.0, 0.0), pos2(70.0, 20.0))
+    );
+}
+
+#[test]
+fn rangef_about_origin() {
+    let range = Rangef::new(12.0, 16.0);
+    assert_eq!(range.scaled_about(10.0, 2.0), Rangef::new(14.0, 22.0));
+    assert_eq!(range.scaled_about(14.0, 0.5), Rangef::new(13.0, 15.0));
+}
@@ -0,0 +1,52 @@
+//! Checks transitions of [`egui_scale::AnimatedScale`].
+
+use egui::{Context, RawInput};
+use egui_scale::{AnimatedScale, EguiScaleContext};
+
+const DT: f64 = 1.0 / 60.0;
+
+/// Runs one frame at the given time and updates the animation in it.
+fn update(ctx: &Context, scale: &mut AnimatedScale, time: f64) -> f32 {
+    let mut applied = 0.0;
+    let _ = ctx.run(
+        RawInput {
+            time: Some(time),
+            ..RawInput::default()
+        },
+        |ctx| applied = scale.update(ctx),
+    );
+    applied
+}
+
+#[test]
+fn retarget_moves_monotonically() {
+    let ctx = Context::default();
+    let mut scale = AnimatedScale::new("zoom", 1.0).with_duration(0.2);
+    let mut time = 0.0;
+    assert_eq!(update(&ctx, &mut scale, time), 1.0);
+
+    scale.set_target(2.0);
+    let mut factors = Vec::new();
+    for _ in 0..6 {
+        factors.push(update(&ctx, &mut scale, time));
+        time += DT;
+    }
+    let midway = *factors.last().unwrap();
+    assert!(midway > 1.0 && midway < 2.0, "{factors:?}");
+
+    // Retargeting continues from the current factor instead of jumping.
+    let retarget_time = time;
+    scale.set_target(3.0);
+    while time <= retarget_time + 0.2 + 1e-6 {
+        factors.push(update(&ctx, &mut scale, time));
+        time += DT;
+    }
+
+    assert!(
+        factors.windows(2).all(|pair| pair[0] <= pair[1]),
+        "{factors:?}"
+    );
+    assert_eq!(*factors.last().unwrap(), 3.0, "{factors:?}");
+    assert_eq!(scale.current(), 3.0);
+    assert_eq!(ctx.scale_factor(), 3.0);
+}
@@ -0,0 +1,90 @@
+//! Checks per-category clamping of scaled style values.
+
+use egui::{CornerRadius, Rangef, Style, TextStyle};
+use egui_scale::{EguiScaleWith, ScaleClamps, ScaleOptions};
+
+fn scaled(scale: f32, clamps: ScaleClamps) -> Style {
+    Style::default().scaled_with_options(&ScaleOptions::uniform(scale).with_clamps(clamps))
+}
+
+#[test]
+fn text() {
+    let clamps = ScaleClamps {
+        text: Rangef::new(9.0, 72.0),
+        ..ScaleClamps::NONE
+    };
+
+    let small = scaled(0.2, clamps);
+    assert_eq!(small.text_styles[&TextStyle::Body].size, 9.0);
+
+    let large = scaled(50.0, clamps);
+    assert_eq!(large.text_styles[&TextStyle::Heading].size, 72.0);
+}
+
+#[test]
+fn strokes_and_corner_radii() {
+    let clamps = ScaleClamps {
+        stroke: Rangef::new(0.0, 4.0),
+        corner_radius: Rangef::new(0.0, 16.0),
+        ..ScaleClamps::NONE
+    };
+
+    let style = scaled(50.0, clamps);
+    assert_eq!(style.visuals.window_stroke.width, 4.0);
+    assert_eq!(style.visuals.window_corner_radius, CornerRadius::same(16));
+}
+
+#[test]
+fn spacing_does_not_collapse_widths() {
+    let clamps = ScaleClamps {
+        spacing: Rangef::new(0.0, 24.0),
+        ..ScaleClamps::NONE
+    };
+
+    let style = scaled(4.0, clamps);
+    let default = Style::default();
+    assert_eq!(style.spacing.item_spacing.x, 24.0);
+    assert_eq!(
+        style.spacing.text_edit_width,
+        default.spacing.text_edit_width * 4.0
+    );
+    assert_eq!(
+        style.spacing.slider_width,
+        default.spacing.slider_width * 4.0
+    );
+}
+
+#[test]
+fn widths() {
+    let clamps = ScaleClamps {
+        width: Rangef::new(0.0, 300.0),
+        ..ScaleClamps::NONE
+    };
+
+    let style = scaled(4.0, clamps);
+    assert_eq!(style.spacing.text_edit_width, 300.0);
+    assert_eq!(style.spacing.menu_width, 300.0);
+    assert_eq!(
+        style.spacing.item_spacing,
+        Style::default().spacing.item_spacing * 4.0
+    );
+}
+
+#[test]
+fn zeros_stay_zero() {
+    let clamps = ScaleClamps {
+        spacing: Rangef::new(2.0, 100.0),
+        stroke: Rangef::new(1.0, 4.0),
+        corner_radius: Rangef::new(2.0, 16.0),
+        ..ScaleClamps::NONE
+    };
+
+    let default = Style::default();
+    assert_eq!(default.visuals.widgets.inactive.expansion, 0.0);
+    assert_eq!(default.visuals.widgets.inactive.bg_stroke.width, 0.0);
+
+    let style = scaled(1.0, clamps);
+    assert_eq!(style.visuals.widgets.inactive.expansion, 0.0);
+    assert_eq!(style.visuals.widgets.inactive.bg_stroke.width, 0.0);
+    assert_eq!(style.visuals.widgets.hovered.expansion, 2.0);
+}
@@ -0,0 +1,11 @@
+//! Helpers shared by integration tests.
+
+use egui::Style;
+use serde_json::Value;
+
+/// Returns the style as a JSON value for comparison.
+///
+/// `Style` is compared through serde, since its number formatter is compared by pointer.
+pub fn value(style: &Style) -> Value {
+    serde_json::to_value(style).unwrap()
+}
@@ -0,0 +1,173 @@
+//! Checks that scaling a [`egui::Style`] touches every length-valued field.
+//!
+//! Every number in the style is replaced with a non-zero value,
+//! then the style is scaled and each number is expected to either change
+//! or be listed as a known non-length field below.
+//! When an egui upgrade adds a new numeric field, this test fails until
+//! the field is either scaled or added to the list.
+//!
+//! Time scaling is checked the same way against the list of durations,
+//! and interpolation is expected to blend every number and flag.
+
+mod common;
+
+use common::value;
+use egui::{vec2, Style};
+use egui_scale::{EguiLerp, EguiScale, EguiScaleTime, EguiScaleXY};
+use serde_json::Value;
+
+/// Numeric fields that are durations and must only be scaled in time.
+const DURATIONS: &[&str] = &[
+    "animation_time",
+    "scroll_animation.duration.min",
+    "scroll_animation.duration.max",
+    "interaction.tooltip_delay",
+    "interaction.tooltip_grace_time",
+    "visuals.text_cursor.on_duration",
+    "visuals.text_cursor.off_duration",
+];
+
+/// Numeric fields that are neither lengths nor durations and must not be scaled.
+const NOT_LENGTHS: &[&str] = &[
+    // Opacities and ratios.
+    "spacing.scroll.dormant_background_opacity",
+    "spacing.scroll.active_background_opacity",
+    "spacing.scroll.interact_background_opacity",
+    "spacing.scroll.dormant_handle_opacity",
+    "spacing.scroll.active_handle_opacity",
+    "spacing.scroll.interact_handle_opacity",
+    "visuals.weak_text_alpha",
+    "visuals.disabled_alpha",
+];
+
+/// Suffixes of color fields.
+const COLORS: &[&str] = &["color", "fill"];
+
+fn collect(value: &Value, path: &str, out: &mut Vec<(String, f64)>) {
+    match value {
+        Value::Number(number) => out.push((path.to_owned(), number.as_f64().unwrap())),
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect(item, &format!("{path}[{index}]"), out);
+            }
+        }
+        Value::Object(fields) => {
+            for (name, field) in fields {
+                let path = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{path}.{name}")
+                };
+                collect(field, &path, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn fill(value: &mut Value, number: u8, flag: bool) {
+    match value {
+        Value::Number(value) => *value = number.into(),
+        Value::Bool(value) => *value = flag,
+        Value::Array(items) => items.iter_mut().for_each(|item| fill(item, number, flag)),
+        Value::Object(fields) => fields
+            .values_mut()
+            .for_each(|field| fill(field, number, flag)),
+        _ => {}
+    }
+}
+
+fn is_known(path: &str) -> bool {
+    let field = path.split('[').next().unwrap();
+    DURATIONS.iter().any(|known| field.starts_with(known))
+        || NOT_LENGTHS.iter().any(|known| field.starts_with(known))
+        || COLORS.iter().any(|color| field.ends_with(color))
+}
+
+fn numbers(style: &Style) -> Vec<(String, f64)> {
+    let mut out = Vec::new();
+    collect(&serde_json::to_value(style).unwrap(), "", &mut out);
+    out
+}
+
+fn filled_style(number: u8, flag: bool) -> Style {
+    let mut value = serde_json::to_value(Style::default()).unwrap();
+    fill(&mut value, number, flag);
+    serde_json::from_value(value).unwrap()
+}
+
+fn assert_all_scaled(before: &Style, after: &Style) {
+    let before = numbers(before);
+    let after = numbers(after);
+    assert_eq!(before.len(), after.len());
+
+    let unscaled = before
+        .iter()
+        .zip(&after)
+        .filter(|((_, before), (_, after))| before == after)
+        .map(|((path, _), _)| path.as_str())
+        .filter(|path| !is_known(path))
+        .collect::<Vec<_>>();
+
+    assert!(unscaled.is_empty(), "unscaled length fields: {unscaled:#?}");
+}
+
+#[test]
+fn every_length_is_scaled() {
+    let style = filled_style(3, false);
+    assert_all_scaled(&style, &style.clone().scaled(2.0));
+}
+
+#[test]
+fn every_length_is_scaled_xy() {
+    let style = filled_style(3, false);
+    assert_all_scaled(&style, &style.clone().scaled_xy(vec2(2.0, 3.0)));
+}
+
+#[test]
+fn xy_follows_direction() {
+    let style = filled_style(3, false).scaled_xy(vec2(2.0, 3.0));
+    let spacing = &style.spacing;
+
+    for margin in [spacing.window_margin, spacing.menu_margin] {
+        assert_eq!((margin.left, margin.right), (6, 6));
+        assert_eq!((margin.top, margin.bottom), (9, 9));
+    }
+    assert_eq!(spacing.item_spacing, vec2(6.0, 9.0));
+    assert_eq!(spacing.button_padding, vec2(6.0, 9.0));
+    assert_eq!(spacing.interact_size, vec2(6.0, 9.0));
+    assert_eq!(spacing.combo_width, 6.0);
+    assert_eq!(spacing.slider_width, 6.0);
+    assert_eq!(spacing.combo_height, 9.0);
+    assert_eq!(spacing.slider_rail_height, 9.0);
+}
+
+#[test]
+fn only_durations_are_scaled_time() {
+    let style = filled_style(3, false);
+    let before = numbers(&style);
+    let after = numbers(&style.scaled_time(2.0));
+
+    let mut changed = before
+        .iter()
+        .zip(&after)
+        .filter(|((_, before), (_, after))| before != after)
+        .map(|((path, _), _)| path.as_str())
+        .collect::<Vec<_>>();
+    changed.sort_unstable();
+
+    let mut expected = DURATIONS.to_vec();
+    expected.push("scroll_animation.points_per_second");
+    expected.sort_unstable();
+
+    assert_eq!(changed, expected);
+}
+
+#[test]
+fn every_field_is_lerped() {
+    let from = filled_style(3, false);
+    let to = filled_style(5, true);
+    let expected = filled_style(4, true);
+
+    assert_eq!(value(&from.lerped(&to, 0.5)), value(&expected),);
+}
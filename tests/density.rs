@@ -0,0 +1,92 @@
+//! Checks that [`egui_scale::Density`] changes spacing only.
+
+mod common;
+
+use common::value;
+use egui::Style;
+use egui_scale::{Density, EguiScale};
+
+#[test]
+fn only_spacing_changes() {
+    let base = Style::default();
+    for density in [Density::Compact, Density::Spacious] {
+        let style = density.applied(base.clone());
+
+        assert_ne!(value(&style), value(&base));
+        assert_eq!(
+            style.spacing.item_spacing,
+            base.spacing.item_spacing * density.spacing()
+        );
+        assert_eq!(
+            style.spacing.scroll.bar_width,
+            base.spacing.scroll.bar_width * density.spacing()
+        );
+        assert_eq!(
+            style.spacing.indent,
+            base.spacing.indent * density.spacing()
+        );
+        assert_eq!(
+            style.spacing.interact_size,
+            base.spacing.interact_size * density.spacing()
+        );
+        assert_ne!(style.spacing.window_margin, base.spacing.window_margin);
+        assert_ne!(style.spacing.button_padding, base.spacing.button_padding);
+
+        let widths = |style: &Style| {
+            let spacing = &style.spacing;
+            [
+                spacing.text_edit_width,
+                spacing.tooltip_width,
+                spacing.slider_width,
+                spacing.combo_width,
+                spacing.menu_width,
+                spacing.default_area_size.x,
+                spacing.default_area_size.y,
+                spacing.icon_width,
+                spacing.icon_width_inner,
+                spacing.icon_spacing,
+            ]
+        };
+        assert_eq!(widths(&style), widths(&base));
+
+        let mut spacing_only = base.clone();
+        spacing_only.spacing = style.spacing.clone();
+        assert_eq!(value(&style), value(&spacing_only));
+
+        assert_eq!(style.visuals, base.visuals);
+        assert_eq!(
+            style.visuals.widgets.inactive.expansion,
+            base.visuals.widgets.inactive.expansion
+        );
+        assert_eq!(
+            style.visuals.clip_rect_margin,
+            base.visuals.clip_rect_margin
+        );
+        assert_eq!(style.scroll_animation, base.scroll_animation);
+        assert_eq!(style.text_styles, base.text_styles);
+    }
+}
+
+#[test]
+fn comfortable_is_identity() {
+    let base = Style::default();
+    assert_eq!(
+        value(&Density::Comfortable.applied(base.clone())),
+        value(&base)
+    );
+}
+
+#[test]
+fn combines_with_zoom() {
+    let base = Style::default();
+    let style = Density::Compact.applied(base.clone()).scaled(2.0);
+    let zoomed = base.clone().scaled(2.0);
+
+    assert_eq!(
+        style.spacing.item_spacing,
+        base.spacing.item_spacing * 0.75 * 2.0
+    );
+    assert_eq!(style.visuals, zoomed.visuals);
+    assert_eq!(style.scroll_animation, zoomed.scroll_animation);
+    assert_eq!(style.text_styles, zoomed.text_styles);
+}
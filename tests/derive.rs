@@ -0,0 +1,135 @@
+//! Checks code generated by `#[derive(EguiScale)]`.
+
+#![cfg(feature = "derive")]
+
+use egui::{Margin, Stroke};
+use egui_scale::EguiScale;
+
+#[derive(EguiScale)]
+struct Named {
+    width: f32,
+    margin: Margin,
+    stroke: Stroke,
+    #[egui_scale(min = 12.0, max = 64.0)]
+    icon: f32,
+    #[egui_scale(skip)]
+    columns: f32,
+    #[egui_scale(with = scale_sqrt)]
+    ratio: f32,
+}
+
+fn scale_sqrt(value: &mut f32, scale: f32) {
+    *value *= scale.sqrt();
+}
+
+#[derive(EguiScale)]
+struct Tuple(f32, #[egui_scale(skip)] f32);
+
+#[derive(Debug, PartialEq, EguiScale)]
+enum Shape {
+    Circle { radius: f32 },
+    Square(f32),
+    Empty,
+}
+
+/// Only checks that the derive compiles for enums without variants.
+#[allow(dead_code)]
+#[derive(EguiScale)]
+enum Never {}
+
+#[derive(EguiScale)]
+struct Generic<T, U> {
+    value: Option<T>,
+    #[egui_scale(skip)]
+    tag: U,
+}
+
+/// Type that does not implement `EguiScale`.
+struct Opaque;
+
+#[derive(EguiScale)]
+struct WithOnly<T> {
+    #[egui_scale(with = scale_opaque)]
+    value: T,
+}
+
+fn scale_opaque<T>(_: &mut T, _: f32) {}
+
+mod renamed {
+    pub use egui_scale as scale_crate;
+}
+
+#[derive(EguiScale)]
+#[egui_scale(crate = renamed::scale_crate)]
+struct Renamed {
+    value: f32,
+}
+
+#[test]
+fn named_fields() {
+    let named = Named {
+        width: 10.0,
+        margin: Margin::same(4),
+        stroke: Stroke::new(2.0, egui::Color32::WHITE),
+        icon: 16.0,
+        columns: 3.0,
+        ratio: 2.0,
+    };
+
+    let doubled = named.scaled(2.0);
+    assert_eq!(doubled.width, 20.0);
+    assert_eq!(doubled.margin, Margin::same(8));
+    assert_eq!(doubled.stroke.width, 4.0);
+    assert_eq!(doubled.icon, 32.0);
+    assert_eq!(doubled.columns, 3.0);
+    assert_eq!(doubled.ratio, 2.0 * 2.0f32.sqrt());
+}
+
+#[test]
+fn min_max_clamp() {
+    let named = |icon| Named {
+        width: 0.0,
+        margin: Margin::ZERO,
+        stroke: Stroke::NONE,
+        icon,
+        columns: 0.0,
+        ratio: 1.0,
+    };
+
+    assert_eq!(named(16.0).scaled(0.5).icon, 12.0);
+    assert_eq!(named(16.0).scaled(8.0).icon, 64.0);
+}
+
+#[test]
+fn tuple_fields() {
+    let tuple = Tuple(2.0, 2.0).scaled(3.0);
+    assert_eq!((tuple.0, tuple.1), (6.0, 2.0));
+}
+
+#[test]
+fn enum_variants() {
+    assert_eq!(
+        Shape::Circle { radius: 2.0 }.scaled(2.0),
+        Shape::Circle { radius: 4.0 }
+    );
+    assert_eq!(Shape::Square(3.0).scaled(2.0), Shape::Square(6.0));
+    assert_eq!(Shape::Empty.scaled(2.0), Shape::Empty);
+}
+
+#[test]
+fn generics() {
+    let Generic { value, tag: Opaque } = Generic {
+        value: Some(2.0f32),
+        tag: Opaque,
+    }
+    .scaled(2.0);
+    assert_eq!(value, Some(4.0));
+
+    let _ = WithOnly { value: Opaque }.scaled(2.0);
+    let _ = Renamed { value: 1.0 }.scaled(2.0).value;
+}
+
+#[test]
+fn compile_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
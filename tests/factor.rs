@@ -0,0 +1,105 @@
+//! Checks that invalid factors are rejected before they reach any style.
+
+mod common;
+
+use common::value;
+use egui::{Context, Margin, Style, Vec2};
+use egui_scale::{
+    AnimatedScale, EguiScale, EguiScaleContext, ScaleFactor, ScaleFactorError, ScaledStyle,
+};
+
+/// Factors that must be rejected, with the expected errors and messages.
+fn invalid() -> [(f32, ScaleFactorError, &'static str); 5] {
+    [
+        (f32::NAN, ScaleFactorError::NaN, "got NaN"),
+        (
+            f32::INFINITY,
+            ScaleFactorError::Infinite(f32::INFINITY),
+            "got inf",
+        ),
+        (
+            f32::NEG_INFINITY,
+            ScaleFactorError::Infinite(f32::NEG_INFINITY),
+            "got -inf",
+        ),
+        (0.0, ScaleFactorError::Zero, "got zero"),
+        (-1.5, ScaleFactorError::Negative(-1.5), "got -1.5"),
+    ]
+}
+
+#[test]
+fn errors() {
+    for (factor, error, message) in invalid() {
+        // `NaN` never equals itself, so errors are compared by their debug output.
+        let result = ScaleFactor::new(factor);
+        assert_eq!(format!("{result:?}"), format!("{:?}", Err::<(), _>(error)));
+        assert_eq!(
+            error.to_string(),
+            format!("scale factor must be finite and positive, {message}")
+        );
+    }
+
+    assert_eq!(ScaleFactor::new(1.5).map(ScaleFactor::get), Ok(1.5));
+    assert_eq!(ScaleFactor::try_from(-0.0), Err(ScaleFactorError::Zero));
+}
+
+#[test]
+fn try_scale_leaves_value_untouched() {
+    for (factor, error, _) in invalid() {
+        let mut vec = Vec2::new(2.0, 3.0);
+        assert_eq!(
+            format!("{:?}", vec.try_scale(factor)),
+            format!("{:?}", Err::<(), _>(error))
+        );
+        assert_eq!(vec, Vec2::new(2.0, 3.0));
+
+        let mut margin = Margin::same(4);
+        assert!(margin.try_scale(factor).is_err());
+        assert_eq!(margin, Margin::same(4));
+    }
+
+    assert_eq!(Vec2::new(2.0, 3.0).try_scaled(2.0), Ok(Vec2::new(4.0, 6.0)));
+}
+
+#[test]
+fn try_set_scale_leaves_context_untouched() {
+    let ctx = Context::default();
+    ctx.set_scale(1.5);
+    let before = value(&ctx.style());
+
+    for (factor, _, _) in invalid() {
+        assert!(ctx.try_set_scale(factor).is_err());
+        assert_eq!(ctx.scale_factor(), 1.5);
+        assert_eq!(value(&ctx.style()), before);
+    }
+
+    assert_eq!(ctx.try_set_scale(2.0), Ok(()));
+    assert_eq!(ctx.scale_factor(), 2.0);
+}
+
+#[test]
+fn try_set_factor_leaves_scaled_style_untouched() {
+    let mut scaled = ScaledStyle::with_factor(Style::default(), 1.5);
+    let before = value(scaled.style());
+
+    for (factor, _, _) in invalid() {
+        assert!(scaled.try_set_factor(factor).is_err());
+        assert_eq!(value(scaled.style()), before);
+    }
+
+    assert_eq!(scaled.try_set_factor(1.0), Ok(()));
+    assert_eq!(value(scaled.style()), value(&Style::default()));
+}
+
+#[test]
+fn try_set_target_keeps_target() {
+    let mut animated = AnimatedScale::new("factor", 1.5);
+
+    for (factor, _, _) in invalid() {
+        assert!(animated.try_set_target(factor).is_err());
+        assert_eq!(animated.target(), 1.5);
+    }
+
+    assert_eq!(animated.try_set_target(2.0), Ok(()));
+    assert_eq!(animated.target(), 2.0);
+}
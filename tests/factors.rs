@@ -0,0 +1,47 @@
+//! Checks that per-category factors leave other categories untouched.
+
+use egui::{Style, TextStyle};
+use egui_scale::{EguiScaleWith, ScaleFactors};
+
+#[test]
+fn text_only() {
+    let base = Style::default();
+    let style = base.clone().scaled_with(&ScaleFactors {
+        text: 1.5,
+        ..ScaleFactors::IDENTITY
+    });
+
+    for (text_style, font_id) in &style.text_styles {
+        assert_eq!(font_id.size, base.text_styles[text_style].size * 1.5);
+    }
+    assert_eq!(
+        style.text_styles[&TextStyle::Body].size,
+        base.text_styles[&TextStyle::Body].size * 1.5
+    );
+    assert_eq!(style.spacing, base.spacing);
+    assert_eq!(style.visuals, base.visuals);
+    assert_eq!(style.interaction, base.interaction);
+    assert_eq!(style.scroll_animation, base.scroll_animation);
+}
+
+#[test]
+fn spacing_only() {
+    let base = Style::default();
+    let style = base.clone().scaled_with(&ScaleFactors {
+        spacing: 2.0,
+        ..ScaleFactors::IDENTITY
+    });
+
+    assert_eq!(style.spacing.item_spacing, base.spacing.item_spacing * 2.0);
+    assert_eq!(style.text_styles, base.text_styles);
+    assert_eq!(
+        style.visuals.widgets.inactive.bg_stroke,
+        base.visuals.widgets.inactive.bg_stroke
+    );
+    assert_eq!(
+        style.visuals.window_corner_radius,
+        base.visuals.window_corner_radius
+    );
+    assert_eq!(style.visuals.window_shadow, base.visuals.window_shadow);
+    assert_eq!(style.interaction, base.interaction);
+}
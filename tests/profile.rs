@@ -0,0 +1,161 @@
+//! Checks that scale profiles survive a round trip through JSON and TOML settings files.
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::value;
+use egui::{Rangef, Style};
+use egui_scale::{
+    FadeSpace, HairlinePolicy, RoundingMode, RoundingPolicy, ScaleClamps, ScaleFactor,
+    ScaleFactors, ScaleProfile,
+};
+
+#[test]
+fn profile_round_trip() {
+    let profile = ScaleProfile {
+        scale: ScaleFactor::new(1.5).unwrap(),
+        factors: ScaleFactors {
+            text: 1.25,
+            ..ScaleFactors::IDENTITY
+        },
+        clamps: ScaleClamps {
+            text: Rangef::new(9.0, 72.0),
+            ..ScaleClamps::NONE
+        },
+        rounding: RoundingPolicy::NEAREST.keep_non_zero(),
+        hairline: HairlinePolicy::Thin,
+        min_touch_target: Some(44.0),
+    };
+
+    let json = serde_json::to_string(&profile).unwrap();
+    let loaded: ScaleProfile = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, profile);
+    assert_eq!(
+        value(&loaded.applied(Style::default())),
+        value(&profile.applied(Style::default())),
+    );
+}
+
+#[test]
+fn profile_is_validated() {
+    assert!(serde_json::from_str::<ScaleProfile>(r#"{ "scale": 2.0 }"#).is_ok());
+    assert!(serde_json::from_str::<ScaleProfile>(r#"{ "scale": -1.0 }"#).is_err());
+}
+
+#[test]
+fn category_factors_are_validated() {
+    let profile: ScaleProfile =
+        serde_json::from_str(r#"{ "scale": 1.0, "factors": { "text": 2.0 } }"#).unwrap();
+    assert_eq!(profile.factors.text, 2.0);
+    assert_eq!(profile.factors.spacing, 1.0);
+
+    for factor in ["-2.0", "0.0"] {
+        let json = format!(r#"{{ "scale": 1.0, "factors": {{ "text": {factor} }} }}"#);
+        let err = serde_json::from_str::<ScaleProfile>(&json).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("scale factor must be finite and positive"),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn pixels_per_point_are_not_serialized() {
+    let profile = ScaleProfile {
+        hairline: HairlinePolicy::MinPixels {
+            min_pixels: 1.0,
+            pixels_per_point: 2.0,
+        },
+        ..ScaleProfile::default()
+    };
+
+    let json = serde_json::to_string(&profile).unwrap();
+    assert!(!json.contains("pixels_per_point"), "{json}");
+
+    let loaded: ScaleProfile = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        loaded.hairline,
+        HairlinePolicy::MinPixels {
+            min_pixels: 1.0,
+            pixels_per_point: 1.0,
+        }
+    );
+    assert_eq!(loaded.hairline.with_pixels_per_point(2.0), profile.hairline);
+    assert_eq!(
+        HairlinePolicy::Thin.with_pixels_per_point(2.0),
+        HairlinePolicy::Thin
+    );
+}
+
+#[test]
+fn toml_round_trip() {
+    for (hairline, min_touch_target) in [
+        (
+            HairlinePolicy::Fade {
+                min_width: 0.5,
+                space: FadeSpace::Linear,
+            },
+            None,
+        ),
+        (
+            HairlinePolicy::MinPixels {
+                min_pixels: 1.0,
+                pixels_per_point: 1.0,
+            },
+            Some(44.0),
+        ),
+        (HairlinePolicy::Thin, Some(40.0)),
+        (HairlinePolicy::Keep, None),
+    ] {
+        let profile = ScaleProfile {
+            scale: ScaleFactor::new(1.25).unwrap(),
+            factors: ScaleFactors {
+                text: 1.5,
+                stroke: 0.75,
+                ..ScaleFactors::IDENTITY
+            },
+            clamps: ScaleClamps {
+                text: Rangef::new(9.0, 72.0),
+                spacing: Rangef::new(0.0, f32::INFINITY),
+                stroke: Rangef::new(f32::NEG_INFINITY, 4.0),
+                ..ScaleClamps::NONE
+            },
+            rounding: RoundingPolicy::new(RoundingMode::Ceil).keep_non_zero(),
+            hairline,
+            min_touch_target,
+        };
+
+        let text = toml::to_string(&profile).unwrap();
+        let loaded: ScaleProfile = toml::from_str(&text).unwrap();
+        assert_eq!(loaded, profile, "{text}");
+    }
+}
+
+#[test]
+fn toml_partial_file() {
+    let profile: ScaleProfile = toml::from_str(
+        r#"
+        scale = 1.5
+        min_touch_target = 44.0
+
+        [factors]
+        text = 1.25
+
+        [clamps.text]
+        min = 9.0
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(profile.scale.get(), 1.5);
+    assert_eq!(profile.factors.text, 1.25);
+    assert_eq!(profile.factors.spacing, 1.0);
+    assert_eq!(profile.clamps.text, Rangef::new(9.0, f32::INFINITY));
+    assert_eq!(profile.clamps.spacing, Rangef::EVERYTHING);
+    assert_eq!(profile.min_touch_target, Some(44.0));
+    assert_eq!(profile.hairline, HairlinePolicy::default());
+
+    assert!(toml::from_str::<ScaleProfile>("[factors]\nstroke = -1.0").is_err());
+}
@@ -0,0 +1,84 @@
+//! Checks rounding of integer-backed values.
+
+use egui::{epaint::Shadow, Color32, CornerRadius, Margin};
+use egui_scale::{EguiScale, EguiScaleRounded, RoundingMode, RoundingPolicy};
+
+#[test]
+fn modes() {
+    let round = |mode, value| RoundingPolicy::new(mode).round(value, true);
+
+    assert_eq!(round(RoundingMode::Truncate, 4.5), 4.0);
+    assert_eq!(round(RoundingMode::Nearest, 4.5), 5.0);
+    assert_eq!(round(RoundingMode::Ceil, 4.2), 5.0);
+    assert_eq!(round(RoundingMode::Floor, 4.8), 4.0);
+
+    assert_eq!(round(RoundingMode::Truncate, -4.5), -4.0);
+    assert_eq!(round(RoundingMode::Nearest, -4.5), -5.0);
+    assert_eq!(round(RoundingMode::Ceil, -4.8), -4.0);
+    assert_eq!(round(RoundingMode::Floor, -4.2), -5.0);
+}
+
+#[test]
+fn nearest_margin() {
+    assert_eq!(
+        Margin::same(3).scaled_rounded(1.5, RoundingPolicy::NEAREST),
+        Margin::same(5)
+    );
+    assert_eq!(
+        Margin::same(3).scaled_rounded(1.5, RoundingPolicy::TRUNCATE),
+        Margin::same(4)
+    );
+}
+
+#[test]
+fn default_matches_eguiscale() {
+    assert_eq!(
+        Margin::same(3).scaled_rounded(1.5, RoundingPolicy::default()),
+        Margin::same(3).scaled(1.5)
+    );
+    assert_eq!(
+        CornerRadius::same(7).scaled_rounded(0.7, RoundingPolicy::default()),
+        CornerRadius::same(7).scaled(0.7)
+    );
+}
+
+#[test]
+fn saturation() {
+    assert_eq!(
+        CornerRadius::same(200).scaled_rounded(4.0, RoundingPolicy::NEAREST),
+        CornerRadius::same(u8::MAX)
+    );
+    assert_eq!(
+        Margin::same(100).scaled_rounded(4.0, RoundingPolicy::NEAREST),
+        Margin::same(i8::MAX)
+    );
+    assert_eq!(
+        Margin::same(-100).scaled_rounded(4.0, RoundingPolicy::NEAREST),
+        Margin::same(i8::MIN)
+    );
+}
+
+#[test]
+fn keep_non_zero() {
+    let keep = RoundingPolicy::TRUNCATE.keep_non_zero();
+
+    assert_eq!(Margin::same(1).scaled_rounded(0.5, keep), Margin::same(1));
+    assert_eq!(Margin::same(-1).scaled_rounded(0.5, keep), Margin::same(-1));
+    assert_eq!(Margin::same(-3).scaled_rounded(0.1, keep), Margin::same(-1));
+    assert_eq!(Margin::ZERO.scaled_rounded(0.5, keep), Margin::ZERO);
+    assert_eq!(
+        Margin::same(1).scaled_rounded(0.5, RoundingPolicy::TRUNCATE),
+        Margin::ZERO
+    );
+
+    let shadow = Shadow {
+        offset: [-2, 2],
+        blur: 1,
+        spread: 0,
+        color: Color32::BLACK,
+    }
+    .scaled_rounded(0.25, keep);
+    assert_eq!(shadow.offset, [-1, 1]);
+    assert_eq!(shadow.blur, 1);
+    assert_eq!(shadow.spread, 0);
+}
@@ -0,0 +1,30 @@
+//! Checks that [`egui_scale::ScaledStyle`] never drifts from its base style.
+
+mod common;
+
+use common::value;
+use egui::Style;
+use egui_scale::ScaledStyle;
+
+#[test]
+fn rescaling_back_restores_base() {
+    let mut scaled = ScaledStyle::new(Style::default());
+    scaled.set_factor(1.5);
+    assert_ne!(value(scaled.style()), value(scaled.base()));
+
+    scaled.set_factor(1.0);
+    assert_eq!(value(scaled.style()), value(scaled.base()));
+    assert_eq!(value(scaled.style()), value(&Style::default()));
+}
+
+#[test]
+fn repeated_rescaling_does_not_compound() {
+    let mut scaled = ScaledStyle::new(Style::default());
+    for factor in [0.75, 1.3, 2.0, 0.5, 1.7] {
+        scaled.set_factor(factor);
+    }
+    scaled.set_factor(1.25);
+
+    let direct = ScaledStyle::with_factor(Style::default(), 1.25);
+    assert_eq!(value(scaled.style()), value(direct.style()));
+}
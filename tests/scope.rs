@@ -0,0 +1,83 @@
+//! Checks scaled scopes of [`egui::Ui`].
+
+use egui::{CentralPanel, Context, RawInput, TextStyle, Ui};
+use egui_scale::{EguiScaleContext, EguiScaleUi};
+
+/// Runs one frame and calls `f` with the central panel `Ui`.
+fn run(ctx: &Context, mut f: impl FnMut(&mut Ui)) {
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| f(ui));
+    });
+}
+
+#[test]
+fn nested_scopes_multiply() {
+    let ctx = Context::default();
+    let mut spacing = Vec::new();
+
+    run(&ctx, |ui| {
+        let item_spacing = |ui: &Ui| ui.spacing().item_spacing.x;
+        spacing.push(item_spacing(ui));
+        ui.scaled_scope(2.0, |ui| {
+            spacing.push(item_spacing(ui));
+            ui.scaled_scope(1.5, |ui| {
+                spacing.push(item_spacing(ui));
+                ui.scaled_scope(2.0, |ui| spacing.push(item_spacing(ui)));
+                spacing.push(item_spacing(ui));
+            });
+            spacing.push(item_spacing(ui));
+        });
+        spacing.push(item_spacing(ui));
+    });
+
+    assert_eq!(spacing, [8.0, 16.0, 24.0, 48.0, 24.0, 16.0, 8.0]);
+}
+
+#[test]
+fn parent_style_is_untouched() {
+    let ctx = Context::default();
+    let mut styles = None;
+
+    run(&ctx, |ui| {
+        let before = ui.style().clone();
+        let child = ui.scaled_scope(2.0, |ui| ui.style().clone()).inner;
+        styles = Some((before, child, ui.style().clone()));
+    });
+
+    let (before, child, after) = styles.unwrap();
+    assert_eq!(after.spacing, before.spacing);
+    assert_eq!(after.visuals, before.visuals);
+    assert_eq!(after.text_styles, before.text_styles);
+    assert_eq!(
+        child.spacing.interact_size,
+        before.spacing.interact_size * 2.0
+    );
+    assert_eq!(
+        child.text_styles[&TextStyle::Body].size,
+        before.text_styles[&TextStyle::Body].size * 2.0
+    );
+    assert_eq!(ctx.style().spacing, before.spacing);
+}
+
+#[test]
+fn effective_scale_multiplies_context_and_scopes() {
+    let ctx = Context::default();
+    ctx.set_scale(1.5);
+    let mut scales = Vec::new();
+
+    run(&ctx, |ui| {
+        scales.push(ui.effective_scale());
+        ui.scaled_scope(2.0, |ui| {
+            scales.push(ui.effective_scale());
+            ui.horizontal(|ui| {
+                scales.push(ui.effective_scale());
+                ui.scaled_scope(0.5, |ui| {
+                    ui.vertical(|ui| scales.push(ui.effective_scale()));
+                });
+            });
+        });
+        scales.push(ui.effective_scale());
+    });
+
+    assert_eq!(scales, [1.5, 3.0, 3.0, 1.5, 1.5]);
+}
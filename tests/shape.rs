@@ -0,0 +1,200 @@
+//! Checks scaling of [`egui::Shape`] and epaint shapes about a pivot.
+
+use std::sync::Arc;
+
+use egui::{
+    epaint::{
+        CircleShape, CubicBezierShape, EllipseShape, Mesh, PaintCallback, PathShape, PathStroke,
+        QuadraticBezierShape, RectShape, TextShape,
+    },
+    pos2, vec2, Color32, Context, CornerRadius, FontId, Pos2, RawInput, Rect, Shape, Stroke,
+    StrokeKind,
+};
+use egui_scale::EguiScaleAbout;
+
+const PIVOT: Pos2 = pos2(10.0, 20.0);
+
+fn stroke() -> Stroke {
+    Stroke::new(2.0, Color32::WHITE)
+}
+
+fn scaled(shape: impl Into<Shape>) -> Shape {
+    shape.into().scaled_about(PIVOT, 2.0)
+}
+
+#[test]
+fn rect() {
+    let shape = RectShape::new(
+        Rect::from_min_max(pos2(12.0, 25.0), pos2(20.0, 30.0)),
+        CornerRadius::same(3),
+        Color32::RED,
+        stroke(),
+        StrokeKind::Inside,
+    )
+    .with_blur_width(1.5);
+
+    let Shape::Rect(shape) = scaled(shape) else {
+        panic!("expected rect");
+    };
+    assert_eq!(
+        shape.rect,
+        Rect::from_min_max(pos2(14.0, 30.0), pos2(30.0, 40.0))
+    );
+    assert_eq!(shape.corner_radius, CornerRadius::same(6));
+    assert_eq!(shape.stroke.width, 4.0);
+    assert_eq!(shape.blur_width, 3.0);
+}
+
+#[test]
+fn circle() {
+    let Shape::Circle(shape) = scaled(CircleShape::stroke(pos2(12.0, 25.0), 3.0, stroke())) else {
+        panic!("expected circle");
+    };
+    assert_eq!(shape.center, pos2(14.0, 30.0));
+    assert_eq!(shape.radius, 6.0);
+    assert_eq!(shape.stroke.width, 4.0);
+}
+
+#[test]
+fn ellipse() {
+    let shape = EllipseShape::stroke(pos2(12.0, 25.0), vec2(3.0, 4.0), stroke());
+    let Shape::Ellipse(shape) = scaled(shape) else {
+        panic!("expected ellipse");
+    };
+    assert_eq!(shape.center, pos2(14.0, 30.0));
+    assert_eq!(shape.radius, vec2(6.0, 8.0));
+    assert_eq!(shape.stroke.width, 4.0);
+}
+
+#[test]
+fn line_segment() {
+    let Shape::LineSegment { points, stroke } =
+        scaled(Shape::line_segment([PIVOT, pos2(12.0, 25.0)], stroke()))
+    else {
+        panic!("expected line segment");
+    };
+    assert_eq!(points, [PIVOT, pos2(14.0, 30.0)]);
+    assert_eq!(stroke.width, 4.0);
+}
+
+#[test]
+fn path() {
+    let shape = PathShape::line(vec![PIVOT, pos2(12.0, 25.0)], stroke());
+    let Shape::Path(shape) = scaled(shape) else {
+        panic!("expected path");
+    };
+    assert_eq!(shape.points, [PIVOT, pos2(14.0, 30.0)]);
+    assert_eq!(shape.stroke.width, 4.0);
+}
+
+#[test]
+fn beziers() {
+    let points = [PIVOT, pos2(12.0, 25.0), pos2(8.0, 15.0)];
+    let scaled_points = [PIVOT, pos2(14.0, 30.0), pos2(6.0, 10.0)];
+
+    let shape = QuadraticBezierShape::from_points_stroke(
+        points,
+        false,
+        Color32::TRANSPARENT,
+        PathStroke::from(stroke()),
+    );
+    let Shape::QuadraticBezier(shape) = scaled(shape) else {
+        panic!("expected quadratic bezier");
+    };
+    assert_eq!(shape.points, scaled_points);
+    assert_eq!(shape.stroke.width, 4.0);
+
+    let shape = CubicBezierShape::from_points_stroke(
+        [points[0], points[1], points[2], pos2(11.0, 21.0)],
+        false,
+        Color32::TRANSPARENT,
+        stroke(),
+    );
+    let Shape::CubicBezier(shape) = scaled(shape) else {
+        panic!("expected cubic bezier");
+    };
+    assert_eq!(shape.points[..3], scaled_points);
+    assert_eq!(shape.points[3], pos2(12.0, 22.0));
+    assert_eq!(shape.stroke.width, 4.0);
+}
+
+#[test]
+fn text() {
+    let ctx = Context::default();
+    let _ = ctx.run(RawInput::default(), |_| {});
+    let galley = ctx.fonts(|fonts| {
+        fonts.layout_no_wrap(
+            "text".to_owned(),
+            FontId::proportional(10.0),
+            Color32::WHITE,
+        )
+    });
+    let size = galley.size();
+
+    let mut shape = TextShape::new(pos2(12.0, 25.0), galley, Color32::WHITE);
+    shape.underline = stroke();
+
+    let Shape::Text(shape) = scaled(shape) else {
+        panic!("expected text");
+    };
+    assert_eq!(shape.pos, pos2(14.0, 30.0));
+    assert_eq!(shape.underline.width, 4.0);
+    assert_eq!(shape.galley.size(), size * 2.0);
+}
+
+#[test]
+fn mesh() {
+    let mut mesh = Mesh::default();
+    mesh.colored_vertex(pos2(12.0, 25.0), Color32::WHITE);
+    let original = Shape::mesh(mesh);
+    let Shape::Mesh(shared) = &original else {
+        panic!("expected mesh");
+    };
+
+    // The mesh is shared with the original shape, so it must be copied on write.
+    let Shape::Mesh(mesh) = original.clone().scaled_about(PIVOT, 2.0) else {
+        panic!("expected mesh");
+    };
+    assert_eq!(mesh.vertices[0].pos, pos2(14.0, 30.0));
+    assert_eq!(shared.vertices[0].pos, pos2(12.0, 25.0));
+}
+
+#[test]
+fn vec() {
+    let shape = Shape::Vec(vec![
+        CircleShape::stroke(pos2(12.0, 25.0), 3.0, stroke()).into(),
+        Shape::Vec(vec![Shape::line_segment(
+            [PIVOT, pos2(8.0, 15.0)],
+            stroke(),
+        )]),
+    ]);
+
+    let Shape::Vec(shapes) = scaled(shape) else {
+        panic!("expected vec");
+    };
+    let [Shape::Circle(circle), Shape::Vec(inner)] = shapes.as_slice() else {
+        panic!("expected circle and nested vec");
+    };
+    assert_eq!(circle.center, pos2(14.0, 30.0));
+    assert_eq!(circle.stroke.width, 4.0);
+    let [Shape::LineSegment { points, stroke }] = inner.as_slice() else {
+        panic!("expected line segment");
+    };
+    assert_eq!(*points, [PIVOT, pos2(6.0, 10.0)]);
+    assert_eq!(stroke.width, 4.0);
+}
+
+#[test]
+fn callback() {
+    let shape = Shape::Callback(PaintCallback {
+        rect: Rect::from_min_max(pos2(12.0, 25.0), pos2(20.0, 30.0)),
+        callback: Arc::new(()),
+    });
+    let Shape::Callback(callback) = scaled(shape) else {
+        panic!("expected callback");
+    };
+    assert_eq!(
+        callback.rect,
+        Rect::from_min_max(pos2(14.0, 30.0), pos2(30.0, 40.0))
+    );
+}
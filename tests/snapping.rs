@@ -0,0 +1,116 @@
+//! Checks snapping of scaled lengths to whole physical pixels.
+
+mod common;
+
+use common::value;
+use egui::{CornerRadius, Frame, Margin, Style};
+use egui_scale::{EguiScaleWith, RoundingPolicy, ScaleOptions};
+
+fn assert_whole_pixels(name: &str, points: f32, pixels_per_point: f32) {
+    let pixels = points * pixels_per_point;
+    assert!(
+        (pixels - pixels.round()).abs() < 1e-4,
+        "{name} is {points} points, {pixels} pixels"
+    );
+}
+
+#[test]
+fn lengths_land_on_whole_pixels() {
+    let style =
+        Style::default().scaled_with_options(&ScaleOptions::uniform(1.25).with_pixel_snapping(1.5));
+    let spacing = &style.spacing;
+    let visuals = &style.visuals;
+
+    for (name, points) in [
+        ("item_spacing.x", spacing.item_spacing.x),
+        ("item_spacing.y", spacing.item_spacing.y),
+        ("button_padding.x", spacing.button_padding.x),
+        ("button_padding.y", spacing.button_padding.y),
+        ("interact_size.x", spacing.interact_size.x),
+        ("interact_size.y", spacing.interact_size.y),
+        ("indent", spacing.indent),
+        ("slider_width", spacing.slider_width),
+        ("icon_width", spacing.icon_width),
+        ("icon_spacing", spacing.icon_spacing),
+        ("scroll.bar_width", spacing.scroll.bar_width),
+        ("clip_rect_margin", visuals.clip_rect_margin),
+        ("window_stroke", visuals.window_stroke.width),
+        (
+            "inactive.fg_stroke",
+            visuals.widgets.inactive.fg_stroke.width,
+        ),
+        ("hovered.bg_stroke", visuals.widgets.hovered.bg_stroke.width),
+    ] {
+        assert_whole_pixels(name, points, 1.5);
+    }
+}
+
+#[test]
+fn invalid_pixels_per_point_disable_snapping() {
+    let unsnapped = value(&Style::default().scaled_with_options(&ScaleOptions::uniform(1.25)));
+
+    for pixels_per_point in [0.0, -1.5, f32::NAN, f32::INFINITY] {
+        let options = ScaleOptions::uniform(1.25).with_pixel_snapping(pixels_per_point);
+        let style = Style::default().scaled_with_options(&options);
+        assert_eq!(
+            value(&style),
+            unsnapped,
+            "pixels per point {pixels_per_point}"
+        );
+    }
+}
+
+#[test]
+fn margins_and_corner_radii_land_on_whole_pixels() {
+    let options = ScaleOptions::uniform(1.25).with_pixel_snapping(1.5);
+    let style = Style::default().scaled_with_options(&options);
+    let spacing = &style.spacing;
+    let visuals = &style.visuals;
+
+    let frame = Frame::new()
+        .inner_margin(Margin::same(4))
+        .corner_radius(CornerRadius::same(3))
+        .scaled_with_options(&options);
+    assert_eq!(frame.inner_margin, Margin::same(6));
+    assert_eq!(frame.corner_radius, CornerRadius::same(4));
+    assert_eq!(spacing.window_margin, Margin::same(8));
+
+    for (name, points) in [
+        ("window_margin", f32::from(spacing.window_margin.left)),
+        ("menu_margin", f32::from(spacing.menu_margin.left)),
+        (
+            "window_corner_radius",
+            f32::from(visuals.window_corner_radius.nw),
+        ),
+        (
+            "menu_corner_radius",
+            f32::from(visuals.menu_corner_radius.nw),
+        ),
+        (
+            "inactive.corner_radius",
+            f32::from(visuals.widgets.inactive.corner_radius.nw),
+        ),
+    ] {
+        assert_whole_pixels(name, points, 1.5);
+    }
+}
+
+#[test]
+fn snapped_integers_keep_non_zero() {
+    let options = ScaleOptions::uniform(0.25)
+        .with_rounding(RoundingPolicy::TRUNCATE.keep_non_zero())
+        .with_pixel_snapping(1.5);
+    let margin = Frame::new()
+        .inner_margin(Margin::same(1))
+        .scaled_with_options(&options)
+        .inner_margin;
+    assert_eq!(margin, Margin::same(2));
+
+    // Every whole point spans whole pixels, so the rounding mode applies as usual.
+    let options = ScaleOptions::uniform(1.25).with_pixel_snapping(2.0);
+    let margin = Frame::new()
+        .inner_margin(Margin::same(6))
+        .scaled_with_options(&options)
+        .inner_margin;
+    assert_eq!(margin, Margin::same(7));
+}
@@ -0,0 +1,72 @@
+//! Checks handling of strokes that become thin after scaling.
+
+use egui::{epaint::PathStroke, Color32, Stroke};
+use egui_scale::{EguiScale, FadeSpace, HairlinePolicy};
+
+fn scaled(policy: HairlinePolicy, stroke: Stroke, scale: f32) -> Stroke {
+    let mut stroke = stroke;
+    policy.scale_stroke(&mut stroke, scale);
+    stroke
+}
+
+#[test]
+fn fade_keeps_width_and_fades_color() {
+    let stroke = Stroke::new(1.0, Color32::WHITE).scaled(0.5);
+    assert_eq!(stroke.width, 1.0);
+    assert_eq!(stroke.color, Color32::WHITE.gamma_multiply(0.5));
+    assert_ne!(stroke.color, Color32::WHITE);
+
+    let linear = HairlinePolicy::Fade {
+        min_width: 1.0,
+        space: FadeSpace::Linear,
+    };
+    let stroke = scaled(linear, Stroke::new(1.0, Color32::WHITE), 0.5);
+    assert_eq!(stroke.width, 1.0);
+    assert_eq!(stroke.color, Color32::WHITE.linear_multiply(0.5));
+}
+
+#[test]
+fn fade_does_not_thicken_thin_strokes() {
+    let stroke = Stroke::new(0.5, Color32::WHITE).scaled(0.5);
+    assert_eq!(stroke.width, 0.5);
+    assert_eq!(stroke.color, Color32::WHITE.gamma_multiply(0.5));
+
+    assert_eq!(Stroke::NONE.scaled(0.5), Stroke::NONE);
+    assert_eq!(Stroke::new(2.0, Color32::WHITE).scaled(2.0).width, 4.0);
+}
+
+#[test]
+fn thin() {
+    let stroke = scaled(HairlinePolicy::Thin, Stroke::new(1.0, Color32::WHITE), 0.5);
+    assert_eq!(stroke, Stroke::new(0.5, Color32::WHITE));
+}
+
+#[test]
+fn min_pixels() {
+    let policy = HairlinePolicy::MinPixels {
+        min_pixels: 1.0,
+        pixels_per_point: 2.0,
+    };
+
+    let stroke = scaled(policy, Stroke::new(1.0, Color32::WHITE), 0.25);
+    assert_eq!(stroke, Stroke::new(0.5, Color32::WHITE));
+
+    let stroke = scaled(policy, Stroke::new(1.0, Color32::WHITE), 3.0);
+    assert_eq!(stroke.width, 3.0);
+}
+
+#[test]
+fn keep() {
+    let stroke = scaled(HairlinePolicy::Keep, Stroke::new(1.0, Color32::WHITE), 0.5);
+    assert_eq!(stroke, Stroke::new(1.0, Color32::WHITE));
+}
+
+#[test]
+fn path_stroke() {
+    let stroke = PathStroke::new(1.0, Color32::WHITE).scaled(0.5);
+    assert_eq!(stroke.width, 1.0);
+    assert_eq!(
+        stroke.color,
+        PathStroke::new(1.0, Color32::WHITE.gamma_multiply(0.5)).color
+    );
+}
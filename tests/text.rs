@@ -0,0 +1,88 @@
+//! Checks scaling of text formats, layout jobs and rich text.
+
+use egui::{
+    text::{LayoutJob, TextFormat},
+    Color32, FontId, RichText, Stroke, Style, WidgetText,
+};
+use egui_scale::{EguiScale, EguiScaleText};
+
+fn format() -> TextFormat {
+    TextFormat {
+        font_id: FontId::monospace(10.0),
+        extra_letter_spacing: 1.0,
+        line_height: Some(12.0),
+        expand_bg: 2.0,
+        underline: Stroke::new(1.0, Color32::WHITE),
+        strikethrough: Stroke::new(2.0, Color32::RED),
+        ..TextFormat::default()
+    }
+}
+
+#[test]
+fn text_format() {
+    let format = format().scaled(2.0);
+    assert_eq!(format.font_id, FontId::monospace(20.0));
+    assert_eq!(format.extra_letter_spacing, 2.0);
+    assert_eq!(format.line_height, Some(24.0));
+    assert_eq!(format.expand_bg, 4.0);
+    assert_eq!(format.underline.width, 2.0);
+    assert_eq!(format.strikethrough.width, 4.0);
+}
+
+#[test]
+fn layout_job() {
+    let mut job = LayoutJob::default();
+    job.append("first", 3.0, format());
+    job.append("second", 0.0, format());
+    job.wrap.max_width = 100.0;
+    job.first_row_min_height = 15.0;
+
+    let job = job.scaled(2.0);
+    assert_eq!(job.wrap.max_width, 200.0);
+    assert_eq!(job.first_row_min_height, 30.0);
+    assert_eq!(job.sections[0].leading_space, 6.0);
+    assert_eq!(job.sections[1].leading_space, 0.0);
+    for section in &job.sections {
+        assert_eq!(section.format.font_id.size, 20.0);
+    }
+    assert_eq!(job.text, "firstsecond");
+
+    let unbounded = LayoutJob::default().scaled(2.0);
+    assert_eq!(unbounded.wrap.max_width, f32::INFINITY);
+}
+
+#[test]
+fn rich_text_explicit_size() {
+    let style = Style::default();
+
+    let job = RichText::new("x")
+        .size(10.0)
+        .into_scaled_layout_job(&style, 2.0);
+    assert_eq!(job.sections.len(), 1);
+    assert_eq!(job.sections[0].format.font_id.size, 20.0);
+
+    let job = RichText::new("x")
+        .size(10.0)
+        .line_height(Some(14.0))
+        .extra_letter_spacing(1.5)
+        .underline()
+        .into_scaled_layout_job(&style, 2.0);
+    let format = &job.sections[0].format;
+    assert_eq!(format.font_id.size, 20.0);
+    assert_eq!(format.line_height, Some(28.0));
+    assert_eq!(format.extra_letter_spacing, 3.0);
+    assert!(format.underline.width > 0.0);
+}
+
+#[test]
+fn widget_text() {
+    let style = Style::default();
+
+    let job = WidgetText::from(RichText::new("x").size(10.0)).into_scaled_layout_job(&style, 2.0);
+    assert_eq!(job.sections[0].format.font_id.size, 20.0);
+
+    let mut source = LayoutJob::default();
+    source.append("x", 0.0, format());
+    let job = WidgetText::from(source).into_scaled_layout_job(&style, 2.0);
+    assert_eq!(job.sections[0].format.font_id, FontId::monospace(20.0));
+}
@@ -0,0 +1,38 @@
+//! Checks that time scaling keeps styles valid.
+
+use egui::{style::ScrollAnimation, Style};
+use egui_scale::EguiScaleTime;
+
+#[test]
+fn reduce_motion_stays_finite() {
+    let style = Style::default().scaled_time(0.0);
+    assert_eq!(style.animation_time, 0.0);
+    assert_eq!(style.scroll_animation.duration.max, 0.0);
+    assert_eq!(
+        style.scroll_animation.points_per_second,
+        ScrollAnimation::default().points_per_second
+    );
+
+    let json = serde_json::to_string(&style).unwrap();
+    let loaded: Style = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.scroll_animation, style.scroll_animation);
+    assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+}
+
+#[test]
+fn invalid_factors_keep_scroll_speed() {
+    for scale in [-2.0, f32::NAN, f32::INFINITY] {
+        let animation = ScrollAnimation::default().scaled_time(scale);
+        assert_eq!(
+            animation.points_per_second,
+            ScrollAnimation::default().points_per_second,
+            "factor {scale}"
+        );
+    }
+
+    let slow = ScrollAnimation::default().scaled_time(2.0);
+    assert_eq!(
+        slow.points_per_second,
+        ScrollAnimation::default().points_per_second / 2.0
+    );
+}
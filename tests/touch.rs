@@ -0,0 +1,60 @@
+//! Checks that minimum touch targets enlarge hit targets only.
+
+use egui::{Rangef, Style};
+use egui_scale::{EguiScaleWith, ScaleClamps, ScaleOptions};
+
+fn scaled(options: ScaleOptions) -> Style {
+    Style::default().scaled_with_options(&options)
+}
+
+fn assert_touch_targets(style: &Style, min: f32) {
+    let spacing = &style.spacing;
+    assert_eq!(spacing.interact_size.x, min);
+    assert_eq!(spacing.interact_size.y, min);
+    assert_eq!(spacing.icon_width, min);
+    assert_eq!(spacing.scroll.bar_width, min);
+    assert_eq!(spacing.scroll.floating_width, min);
+    assert_eq!(style.interaction.resize_grab_radius_corner, min / 2.0);
+    assert_eq!(style.interaction.resize_grab_radius_side, min / 2.0);
+}
+
+#[test]
+fn hit_targets_are_raised() {
+    let style = scaled(ScaleOptions::uniform(0.5).with_min_touch_target(44.0));
+    assert_touch_targets(&style, 44.0);
+}
+
+#[test]
+fn larger_targets_are_kept() {
+    let style = scaled(ScaleOptions::uniform(4.0).with_min_touch_target(20.0));
+    let plain = scaled(ScaleOptions::uniform(4.0));
+    assert_eq!(style.spacing.interact_size, plain.spacing.interact_size);
+    assert!(style.spacing.interact_size.x > 20.0);
+}
+
+#[test]
+fn text_and_strokes_are_unchanged() {
+    let style = scaled(ScaleOptions::uniform(0.5).with_min_touch_target(44.0));
+    let plain = scaled(ScaleOptions::uniform(0.5));
+
+    assert_eq!(style.text_styles, plain.text_styles);
+    assert_eq!(style.visuals, plain.visuals);
+    assert_eq!(style.spacing.item_spacing, plain.spacing.item_spacing);
+    assert_eq!(style.spacing.button_padding, plain.spacing.button_padding);
+    assert_eq!(style.spacing.window_margin, plain.spacing.window_margin);
+}
+
+#[test]
+fn applies_after_spacing_clamp() {
+    let options = ScaleOptions::uniform(0.5)
+        .with_clamps(ScaleClamps {
+            spacing: Rangef::new(0.0, 10.0),
+            interaction: Rangef::new(0.0, 4.0),
+            ..ScaleClamps::NONE
+        })
+        .with_min_touch_target(44.0);
+    let style = scaled(options);
+
+    assert_touch_targets(&style, 44.0);
+    assert!(style.spacing.item_spacing.x <= 10.0);
+}
@@ -0,0 +1,85 @@
+//! Checks sizes generated by [`egui_scale::TypeScale`].
+
+use egui::{FontFamily, FontId, Style, TextStyle};
+use egui_scale::TypeScale;
+
+fn named(name: &str) -> TextStyle {
+    TextStyle::Name(name.into())
+}
+
+fn style_with(text_styles: &[(TextStyle, f32)]) -> Style {
+    Style {
+        text_styles: text_styles
+            .iter()
+            .map(|(text_style, size)| (text_style.clone(), FontId::proportional(*size)))
+            .collect(),
+        ..Style::default()
+    }
+}
+
+#[test]
+fn built_in_steps() {
+    let scale = TypeScale::default();
+    let style = scale.applied(Style::default());
+
+    assert_eq!(style.text_styles[&TextStyle::Body].size, 12.5);
+    assert_eq!(style.text_styles[&TextStyle::Button].size, 12.5);
+    assert_eq!(style.text_styles[&TextStyle::Small].size, 10.0);
+    assert_eq!(style.text_styles[&TextStyle::Heading].size, scale.size(2));
+    assert_eq!(
+        style.text_styles[&TextStyle::Monospace],
+        FontId::new(12.5, FontFamily::Monospace)
+    );
+}
+
+#[test]
+fn named_styles_keep_closest_step() {
+    let style = TypeScale::default().applied(style_with(&[
+        (TextStyle::Body, 12.5),
+        (named("title"), 30.0),
+        (named("caption"), 8.0),
+        (named("same"), 12.5),
+    ]));
+
+    assert_eq!(
+        style.text_styles[&named("title")].size,
+        TypeScale::default().size(4)
+    );
+    assert_eq!(
+        style.text_styles[&named("caption")].size,
+        TypeScale::default().size(-2)
+    );
+    assert_eq!(style.text_styles[&named("same")].size, 12.5);
+}
+
+#[test]
+fn named_steps_are_relative_to_current_body() {
+    // 40 points is two major-third steps above a 25 point body.
+    let scale = TypeScale::new(10.0, TypeScale::MAJOR_THIRD);
+    let style = scale.applied(style_with(&[
+        (TextStyle::Body, 25.0),
+        (named("title"), 40.0),
+    ]));
+
+    assert_eq!(style.text_styles[&TextStyle::Body].size, 10.0);
+    assert_eq!(style.text_styles[&named("title")].size, scale.size(2));
+}
+
+#[test]
+fn missing_body_uses_scale_body() {
+    let scale = TypeScale::new(10.0, 2.0);
+    let style = scale.applied(style_with(&[(named("title"), 40.0)]));
+    assert_eq!(style.text_styles[&named("title")].size, 40.0);
+}
+
+#[test]
+fn ratio_of_one_keeps_named_styles_at_body() {
+    let scale = TypeScale::new(14.0, 1.0);
+    let style = scale.applied(style_with(&[
+        (TextStyle::Body, 12.5),
+        (named("title"), 30.0),
+    ]));
+
+    assert_eq!(style.text_styles[&named("title")].size, 14.0);
+    assert_eq!(style.text_styles[&TextStyle::Body].size, 14.0);
+}
@@ -0,0 +1,9 @@
+use egui_scale::EguiScale;
+
+#[derive(EguiScale)]
+struct Clamped {
+    #[egui_scale(skip, min = 1.0)]
+    value: f32,
+}
+
+fn main() {}
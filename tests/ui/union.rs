@@ -0,0 +1,9 @@
+use egui_scale::EguiScale;
+
+#[derive(EguiScale)]
+union Bits {
+    float: f32,
+    int: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use egui_scale::EguiScale;
+
+#[derive(EguiScale)]
+struct Unknown {
+    #[egui_scale(clamp)]
+    value: f32,
+}
+
+fn main() {}
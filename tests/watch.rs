@@ -0,0 +1,106 @@
+//! Checks that [`egui_scale::ProfileWatcher`] reloads profiles without compounding.
+
+#![cfg(feature = "watch")]
+
+mod common;
+
+use std::{
+    fs::{self, File},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use common::value;
+use egui::{Context, RawInput};
+use egui_scale::{
+    EguiScaleContext, ProfileWatchError, ProfileWatcher, ScaleFactor, ScaleOptions, ScaleProfile,
+};
+
+/// Writes the file and moves its modification time forward,
+/// so changes are seen regardless of file system time resolution.
+fn write(path: &PathBuf, text: &str, modified: u64) {
+    fs::write(path, text).unwrap();
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(modified))
+        .unwrap();
+}
+
+/// Runs one frame at the given time and updates the watcher in it.
+fn update(
+    ctx: &Context,
+    watcher: &mut ProfileWatcher,
+    time: f64,
+) -> Result<bool, ProfileWatchError> {
+    let mut result = None;
+    let _ = ctx.run(
+        RawInput {
+            time: Some(time),
+            ..RawInput::default()
+        },
+        |ctx| result = Some(watcher.update(ctx)),
+    );
+    result.unwrap()
+}
+
+#[test]
+fn reloads_changed_profile() {
+    let path = std::env::temp_dir().join(format!("egui-scale-watch-{}.txt", std::process::id()));
+    write(&path, "2.0", 1);
+
+    let ctx = Context::default();
+    ctx.set_scale_options(ScaleOptions::default().with_pixel_snapping(2.0));
+    let mut watcher = ProfileWatcher::new(&path, |text| {
+        Ok(ScaleProfile {
+            scale: ScaleFactor::new(text.trim().parse()?)?,
+            ..ScaleProfile::default()
+        })
+    });
+
+    assert!(update(&ctx, &mut watcher, 0.0).unwrap());
+    assert_eq!(ctx.scale_factor(), 2.0);
+    assert_eq!(ctx.scale_options().snap_pixels_per_point, Some(2.0));
+
+    // Unchanged file and checks within the interval do not reload.
+    assert!(!update(&ctx, &mut watcher, 0.1).unwrap());
+    assert!(!update(&ctx, &mut watcher, 1.0).unwrap());
+
+    write(&path, "1.5", 2);
+    assert!(!update(&ctx, &mut watcher, 1.1).unwrap());
+    assert!(update(&ctx, &mut watcher, 2.0).unwrap());
+    assert_eq!(ctx.scale_factor(), 1.5);
+    assert_eq!(ctx.scale_options().snap_pixels_per_point, Some(2.0));
+
+    let expected = Context::default();
+    expected.set_scale_options(ScaleOptions::default().with_pixel_snapping(2.0));
+    expected.set_scale(1.5);
+    assert_eq!(value(&ctx.style()), value(&expected.style()));
+
+    // A broken file is reported once and keeps the previous profile.
+    write(&path, "big", 3);
+    assert!(matches!(
+        update(&ctx, &mut watcher, 3.0),
+        Err(ProfileWatchError::Parse(_))
+    ));
+    assert!(!update(&ctx, &mut watcher, 4.0).unwrap());
+    assert_eq!(ctx.scale_factor(), 1.5);
+
+    // A missing file is reported once and loaded again when it reappears.
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        update(&ctx, &mut watcher, 5.0),
+        Err(ProfileWatchError::Io(_))
+    ));
+    assert!(!update(&ctx, &mut watcher, 6.0).unwrap());
+    assert!(!update(&ctx, &mut watcher, 7.0).unwrap());
+
+    write(&path, "1.0", 3);
+    assert!(update(&ctx, &mut watcher, 8.0).unwrap());
+    assert_eq!(ctx.scale_factor(), 1.0);
+    expected.set_scale(1.0);
+    assert_eq!(value(&ctx.style()), value(&expected.style()));
+
+    fs::remove_file(&path).unwrap();
+}